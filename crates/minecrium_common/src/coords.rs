@@ -28,14 +28,16 @@
 //! | [`HAxis`]                | 2 kinds of horizontal axes.                                       |
 //! | [`Direction`]            | 6 directions parallel to the 3-dimentional axes.                  |
 //! | [`HDirection`]           | 8 kinds of horizontal directions.                                 |
+//! | [`RelativeDirection`]    | 6 directions relative to a facing direction.                      |
 //! | [`ChunkPosition`]        | Absolute position of a chunk.                                     |
 //! | [`BlockPosition`]        | Absolute position of a block.                                     |
 //! | [`BlockOffset`]          | Relative position of a block in the chunk.                        |
 
+use std::iter::FusedIterator;
 use std::str::FromStr;
 use std::{fmt, ops};
 
-use cgmath::Vector3;
+use cgmath::{InnerSpace, Point3, Vector2, Vector3};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::*;
@@ -43,6 +45,25 @@ use crate::errors::*;
 /// The width of a chunk, in blocks (= `16`).
 pub const CHUNK_WIDTH: usize = 16;
 
+/// The unit offsets of the 6 directions, indexed by the declaration order of [`Direction`] (the
+/// same order as [`Direction::ALL`]).
+///
+/// ```
+/// # use minecrium_common::cgmath::Vector3;
+/// # use minecrium_common::coords::{Direction, DIRECTION_OFFSETS};
+/// #
+/// const UP: Vector3<i32> = DIRECTION_OFFSETS[Direction::Up as usize];
+/// assert_eq!(UP, Vector3::new(0, 1, 0));
+/// ```
+pub const DIRECTION_OFFSETS: [Vector3<i32>; 6] = [
+    Vector3::new(0, 0, 1),
+    Vector3::new(0, 0, -1),
+    Vector3::new(1, 0, 0),
+    Vector3::new(-1, 0, 0),
+    Vector3::new(0, 1, 0),
+    Vector3::new(0, -1, 0),
+];
+
 /// 3 kinds of the 3-dimentional axes, includes "x", "y" and "z".
 ///
 /// See the [`module documentation`](crate::coords) for more details.
@@ -58,6 +79,37 @@ pub enum Axis {
     Y,
 }
 
+impl Axis {
+    /// Returns the next axis in the cycle `x -> y -> z -> x`, e.g. to rotate a pillar block.
+    #[inline]
+    pub const fn cycle(self) -> Self {
+        match self {
+            Self::X => Self::Y,
+            Self::Y => Self::Z,
+            Self::Z => Self::X,
+        }
+    }
+
+    /// Returns the index of the axis in the order of declaration (`z`, `x`, `y`).
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the axis from the index in the order of declaration (`z`, `x`, `y`).
+    ///
+    /// Returns `None` if the index is out of range (`index >= 3`).
+    #[inline]
+    pub const fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::Z),
+            1 => Some(Self::X),
+            2 => Some(Self::Y),
+            _ => None,
+        }
+    }
+}
+
 impl AsRef<str> for Axis {
     fn as_ref(&self) -> &str {
         match self {
@@ -174,6 +226,39 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// All 6 directions, in the order of declaration.
+    pub const ALL: [Direction; 6] = [
+        Self::South,
+        Self::North,
+        Self::East,
+        Self::West,
+        Self::Up,
+        Self::Down,
+    ];
+
+    /// The 4 horizontal directions, in the same order as the cardinal [`HDirection`]s.
+    pub const HORIZONTAL: [Direction; 4] = [Self::South, Self::North, Self::East, Self::West];
+
+    /// The 2 vertical directions.
+    pub const VERTICAL: [Direction; 2] = [Self::Up, Self::Down];
+
+    /// Returns `true` if the direction is horizontal (i.e. not up or down).
+    #[inline]
+    pub const fn is_horizontal(self) -> bool {
+        !matches!(self, Self::Up | Self::Down)
+    }
+
+    /// Returns an iterator over the directions which are not masked.
+    ///
+    /// The `mask` is indexed in the same order as [`Direction::ALL`], e.g. the faces adjacent to
+    /// opaque neighbors when culling faces.
+    pub fn faces_visible(mask: [bool; 6]) -> impl Iterator<Item = Direction> {
+        Self::ALL
+            .into_iter()
+            .zip(mask)
+            .filter_map(|(dir, masked)| (!masked).then_some(dir))
+    }
+
     /// Returns the direction that is opposite to the given direction.
     pub fn opposite(&self) -> Self {
         match self {
@@ -186,6 +271,86 @@ impl Direction {
         }
     }
 
+    /// Returns the direction relative to `self`, where `self` is regarded as the front.
+    ///
+    /// For horizontal fronts, up and down are the vertical directions, and right is clockwise
+    /// from front when viewed from above (e.g. the right of south is west).
+    ///
+    /// For vertical fronts, the directions are pitched from facing north, i.e. the left and
+    /// right are always west and east, and the up of the front `Up` is south (the back of the
+    /// head), while the up of the front `Down` is north.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use minecrium_common::coords::{Direction, RelativeDirection};
+    /// #
+    /// assert_eq!(Direction::South.relative(RelativeDirection::Right), Direction::West);
+    /// assert_eq!(Direction::North.relative(RelativeDirection::Back), Direction::South);
+    /// assert_eq!(Direction::Up.relative(RelativeDirection::Up), Direction::South);
+    /// ```
+    pub fn relative(self, rel: RelativeDirection) -> Self {
+        let up = match self {
+            Self::Up => Self::South,
+            Self::Down => Self::North,
+            _ => Self::Up,
+        };
+        let right = match self {
+            Self::South => Self::West,
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::West => Self::North,
+            Self::Up | Self::Down => Self::East,
+        };
+
+        match rel {
+            RelativeDirection::Front => self,
+            RelativeDirection::Back => self.opposite(),
+            RelativeDirection::Left => right.opposite(),
+            RelativeDirection::Right => right,
+            RelativeDirection::Up => up,
+            RelativeDirection::Down => up.opposite(),
+        }
+    }
+
+    /// Returns the dot product of the unit vectors of both directions, i.e. `1` if they are the
+    /// same, `-1` if they are opposite, otherwise `0`.
+    #[inline]
+    pub fn dot(self, other: Direction) -> i32 {
+        if self == other {
+            1
+        } else if self.is_opposite(other) {
+            -1
+        } else {
+            0
+        }
+    }
+
+    /// Returns `true` if the direction is opposite to the other direction.
+    #[inline]
+    pub fn is_opposite(self, other: Direction) -> bool {
+        self.opposite() == other
+    }
+
+    /// Returns the 3-bit encoding of the direction, ranging from `0` to `6` (exclusive) in the
+    /// order of [`Direction::ALL`].
+    #[inline]
+    pub const fn to_bits(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the direction from the 3-bit encoding, see [`Direction::to_bits`].
+    ///
+    /// Returns `None` if the encoding is not in the range from `0` to `6` (exclusive).
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if (bits as usize) < Self::ALL.len() {
+            Some(Self::ALL[bits as usize])
+        } else {
+            None
+        }
+    }
+
     /// Returns the axis which is parallel to the given direction.
     pub fn axis(&self) -> Axis {
         match self {
@@ -241,15 +406,9 @@ impl FromStr for Direction {
 }
 
 impl From<Direction> for Vector3<i32> {
+    #[inline]
     fn from(value: Direction) -> Self {
-        match value {
-            Direction::South => Vector3::new(0, 0, 1),
-            Direction::North => Vector3::new(0, 0, -1),
-            Direction::East => Vector3::new(1, 0, 0),
-            Direction::West => Vector3::new(-1, 0, 0),
-            Direction::Up => Vector3::new(0, 1, 0),
-            Direction::Down => Vector3::new(0, -1, 0),
-        }
+        DIRECTION_OFFSETS[value as usize]
     }
 }
 
@@ -266,6 +425,78 @@ impl From<Direction> for Vector3<f32> {
     }
 }
 
+/// 6 directions relative to a facing direction, includes "front", "back", "left", "right", "up"
+/// and "down", see [`Direction::relative`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RelativeDirection {
+    /// The facing direction itself.
+    Front,
+    /// The opposite of the facing direction.
+    Back,
+    /// The left-hand side of the facing direction.
+    Left,
+    /// The right-hand side of the facing direction.
+    Right,
+    /// The upside of the facing direction.
+    Up,
+    /// The downside of the facing direction.
+    Down,
+}
+
+impl RelativeDirection {
+    /// All 6 relative directions, in the order of declaration.
+    pub const ALL: [RelativeDirection; 6] = [
+        Self::Front,
+        Self::Back,
+        Self::Left,
+        Self::Right,
+        Self::Up,
+        Self::Down,
+    ];
+}
+
+impl AsRef<str> for RelativeDirection {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Front => "front",
+            Self::Back => "back",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Up => "up",
+            Self::Down => "down",
+        }
+    }
+}
+
+impl fmt::Debug for RelativeDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(<Self as AsRef<str>>::as_ref(self))
+    }
+}
+
+impl fmt::Display for RelativeDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(<Self as AsRef<str>>::as_ref(self))
+    }
+}
+
+impl FromStr for RelativeDirection {
+    type Err = ParseRelativeDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "front" => Ok(Self::Front),
+            "back" => Ok(Self::Back),
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "up" => Ok(Self::Up),
+            "down" => Ok(Self::Down),
+            _ => Err(ParseRelativeDirectionError),
+        }
+    }
+}
+
 /// 8 kinds of horizontal directions, includes "south", "north", "east", "west", "southeast",
 /// "southwest", "northeast", "northwest".
 ///
@@ -308,6 +539,94 @@ pub enum HDirection {
     Northwest,
 }
 
+impl HDirection {
+    /// All 8 directions, in the order of declaration.
+    pub const ALL: [HDirection; 8] = [
+        Self::South,
+        Self::North,
+        Self::East,
+        Self::West,
+        Self::Southeast,
+        Self::Southwest,
+        Self::Northeast,
+        Self::Northwest,
+    ];
+
+    /// Returns the 4-bit encoding of the direction, ranging from `0` to `8` (exclusive) in the
+    /// order of [`HDirection::ALL`].
+    #[inline]
+    pub const fn to_bits(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the direction from the 4-bit encoding, see [`HDirection::to_bits`].
+    ///
+    /// Returns `None` if the encoding is not in the range from `0` to `8` (exclusive).
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if (bits as usize) < Self::ALL.len() {
+            Some(Self::ALL[bits as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the unit vector `(x, z)` of the direction on the horizontal plane.
+    ///
+    /// The diagonal directions are normalized, e.g. `Southeast` is `(√2/2, √2/2)`.
+    pub fn unit_vec(self) -> Vector2<f32> {
+        use std::f32::consts::FRAC_1_SQRT_2;
+
+        match self {
+            Self::South => Vector2::new(0., 1.),
+            Self::North => Vector2::new(0., -1.),
+            Self::East => Vector2::new(1., 0.),
+            Self::West => Vector2::new(-1., 0.),
+            Self::Southeast => Vector2::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+            Self::Southwest => Vector2::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+            Self::Northeast => Vector2::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+            Self::Northwest => Vector2::new(-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+        }
+    }
+
+    /// Returns the angle of the direction in degrees, ranging from `0` to `360` (exclusive).
+    ///
+    /// The angle is measured from south and clockwise (seen from above), which is the same as the
+    /// vanilla yaw, i.e. south is `0`, west is `90`, north is `180` and east is `270`.
+    pub fn angle_deg(self) -> f32 {
+        match self {
+            Self::South => 0.,
+            Self::Southwest => 45.,
+            Self::West => 90.,
+            Self::Northwest => 135.,
+            Self::North => 180.,
+            Self::Northeast => 225.,
+            Self::East => 270.,
+            Self::Southeast => 315.,
+        }
+    }
+
+    /// Returns the direction nearest to the angle in degrees.
+    ///
+    /// The angle is measured the same way as [`HDirection::angle_deg`], and may be out of the
+    /// range from `0` to `360`. Returns `South` if the angle is `NaN`.
+    pub fn from_angle_deg(angle: f32) -> Self {
+        const DIRECTIONS: [HDirection; 8] = [
+            HDirection::South,
+            HDirection::Southwest,
+            HDirection::West,
+            HDirection::Northwest,
+            HDirection::North,
+            HDirection::Northeast,
+            HDirection::East,
+            HDirection::Southeast,
+        ];
+
+        let index = ((angle / 45.).round() as i32).rem_euclid(8);
+        DIRECTIONS[index as usize]
+    }
+}
+
 impl AsRef<str> for HDirection {
     fn as_ref(&self) -> &str {
         match self {
@@ -407,6 +726,39 @@ impl ChunkPosition {
         self.x -= n;
         self
     }
+
+    /// Returns the position where is `n` chunks towards the given direction, or `None` if the
+    /// direction is vertical.
+    #[inline]
+    pub const fn offset(self, dir: Direction, n: i32) -> Option<Self> {
+        match dir {
+            Direction::South => Some(self.south(n)),
+            Direction::North => Some(self.north(n)),
+            Direction::East => Some(self.east(n)),
+            Direction::West => Some(self.west(n)),
+            Direction::Up | Direction::Down => None,
+        }
+    }
+
+    /// Returns the position `(x, z)` of the region (32×32 chunks, e.g. a region file) containing
+    /// the chunk.
+    #[inline]
+    pub const fn region(&self) -> (i32, i32) {
+        (self.x >> 5, self.z >> 5)
+    }
+
+    /// Returns the position `(x, z)` of the chunk in the region, ranging from `0` to `32`
+    /// (exclusive), see [`ChunkPosition::region`].
+    #[inline]
+    pub const fn region_local(&self) -> (u8, u8) {
+        ((self.x & 31) as u8, (self.z & 31) as u8)
+    }
+
+    /// Returns the four horizontal neighbors, in the order of [`Direction::HORIZONTAL`].
+    #[inline]
+    pub const fn neighbors(self) -> [Self; 4] {
+        [self.south(1), self.north(1), self.east(1), self.west(1)]
+    }
 }
 
 impl fmt::Debug for ChunkPosition {
@@ -483,6 +835,60 @@ impl BlockPosition {
         )
     }
 
+    /// Returns the block offset in the chunk.
+    ///
+    /// This method is equivalent to `self.into_parts().1`.
+    #[inline]
+    pub const fn chunk_offset(&self) -> BlockOffset {
+        const WIDTH: i32 = CHUNK_WIDTH as i32;
+
+        BlockOffset::new(
+            self.x.rem_euclid(WIDTH) as u8,
+            self.y as u16,
+            self.z.rem_euclid(WIDTH) as u8,
+        )
+    }
+
+    /// Returns the position of the 16×16×16 section containing the block.
+    ///
+    /// Unlike [`BlockPosition::into_parts`], the y component is also divided (Euclidean) by
+    /// [`CHUNK_WIDTH`], e.g. for section-indexed worlds.
+    #[inline]
+    pub const fn section(&self) -> Point3<i32> {
+        const WIDTH: i32 = CHUNK_WIDTH as i32;
+
+        Point3::new(
+            self.x.div_euclid(WIDTH),
+            self.y.div_euclid(WIDTH),
+            self.z.div_euclid(WIDTH),
+        )
+    }
+
+    /// Returns the position packed into an `i64`, which is compatible with the vanilla format.
+    ///
+    /// The x and z components take 26 bits and the y component takes 12 bits, i.e.
+    /// `(x << 38) | (z << 12) | y`. Components out of range are truncated.
+    #[inline]
+    pub const fn to_long(self) -> i64 {
+        let x = self.x as i64 & 0x3FF_FFFF;
+        let y = self.y as i64 & 0xFFF;
+        let z = self.z as i64 & 0x3FF_FFFF;
+        (x << 38) | (z << 12) | y
+    }
+
+    /// Returns the position unpacked from an `i64`, which is compatible with the vanilla format.
+    ///
+    /// See [`BlockPosition::to_long`] for more details.
+    #[inline]
+    pub const fn from_long(value: i64) -> Self {
+        // the arithmetic right shifts sign-extend the fields.
+        Self {
+            x: (value >> 38) as i32,
+            y: (value << 52 >> 52) as i32,
+            z: (value << 26 >> 38) as i32,
+        }
+    }
+
     /// Returns the position where is `n` blocks south.
     ///
     /// This method is equivalent to `self.north(-n)`.
@@ -536,8 +942,150 @@ impl BlockPosition {
         self.y -= n;
         self
     }
+
+    /// Returns the 6 adjacent positions, each paired with the direction towards it, in the order
+    /// of [`Direction::ALL`].
+    #[inline]
+    pub const fn adjacency(self) -> [(Direction, BlockPosition); 6] {
+        [
+            (Direction::South, self.south(1)),
+            (Direction::North, self.north(1)),
+            (Direction::East, self.east(1)),
+            (Direction::West, self.west(1)),
+            (Direction::Up, self.up(1)),
+            (Direction::Down, self.down(1)),
+        ]
+    }
+
+    /// Returns the position with the absolute value of each component.
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Returns the position with the sign (`-1`, `0` or `1`) of each component.
+    #[inline]
+    pub const fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+        }
+    }
+
+    /// Returns an iterator over all positions within `radius` blocks of the `center`, measured
+    /// between the block centers.
+    ///
+    /// The iterator walks the bounding cube of the sphere in x-y-z order. A negative or `NaN`
+    /// radius yields nothing. The bounding cube is clipped to the range of `i32`, so positions
+    /// never wrap around, and an infinite radius walks every position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use minecrium_common::coords::BlockPosition;
+    /// #
+    /// let center = BlockPosition::new(0, 64, 0);
+    /// assert_eq!(BlockPosition::iter_sphere(center, 0.).count(), 1);
+    /// assert_eq!(BlockPosition::iter_sphere(center, 1.).count(), 7);
+    /// ```
+    pub fn iter_sphere(center: BlockPosition, radius: f32) -> impl Iterator<Item = BlockPosition> {
+        IterSphere::new(center, radius)
+    }
+}
+
+/// An iterator over the positions within a sphere, see [`BlockPosition::iter_sphere`].
+#[derive(Clone, Debug)]
+struct IterSphere {
+    center: BlockPosition,
+    radius_sq: f64,
+    /// the minimum corner of the (clipped) bounding cube.
+    min: BlockPosition,
+    /// the maximum corner of the (clipped) bounding cube.
+    max: BlockPosition,
+    /// the next position in the bounding cube, or `None` if the iterator is exhausted.
+    pos: Option<BlockPosition>,
 }
 
+impl IterSphere {
+    fn new(center: BlockPosition, radius: f32) -> Self {
+        // any extent beyond `u32::MAX` reaches the whole range of `i32` from every center.
+        let extent = if radius >= 0. {
+            (radius as i64).min(u32::MAX as i64)
+        } else {
+            -1
+        };
+
+        let clip = |c: i32| {
+            let lo = (c as i64 - extent).max(i32::MIN as i64);
+            let hi = (c as i64 + extent).min(i32::MAX as i64);
+            (lo as i32, hi as i32)
+        };
+        let (x0, x1) = clip(center.x);
+        let (y0, y1) = clip(center.y);
+        let (z0, z1) = clip(center.z);
+        let min = BlockPosition::new(x0, y0, z0);
+
+        Self {
+            center,
+            radius_sq: radius as f64 * radius as f64,
+            min,
+            max: BlockPosition::new(x1, y1, z1),
+            pos: (extent >= 0).then_some(min),
+        }
+    }
+}
+
+impl Iterator for IterSphere {
+    type Item = BlockPosition;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(pos) = self.pos {
+            // advances the cursors in z-y-x order, like an odometer.
+            self.pos = if pos.z < self.max.z {
+                Some(BlockPosition::new(pos.x, pos.y, pos.z + 1))
+            } else if pos.y < self.max.y {
+                Some(BlockPosition::new(pos.x, pos.y + 1, self.min.z))
+            } else if pos.x < self.max.x {
+                Some(BlockPosition::new(pos.x + 1, self.min.y, self.min.z))
+            } else {
+                None
+            };
+
+            let dx = (pos.x as i64 - self.center.x as i64) as f64;
+            let dy = (pos.y as i64 - self.center.y as i64) as f64;
+            let dz = (pos.z as i64 - self.center.z as i64) as f64;
+            if dx * dx + dy * dy + dz * dz <= self.radius_sq {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Some(pos) = self.pos else {
+            return (0, Some(0));
+        };
+
+        // the number of the remaining positions in the bounding cube.
+        let span = |lo: i32, hi: i32| (hi as i64 - lo as i64) as u64;
+        let size_y = span(self.min.y, self.max.y) + 1;
+        let size_z = span(self.min.z, self.max.z) + 1;
+        let remaining = span(pos.x, self.max.x)
+            .checked_mul(size_y)
+            .and_then(|n| n.checked_add(span(pos.y, self.max.y)))
+            .and_then(|n| n.checked_mul(size_z))
+            .and_then(|n| n.checked_add(span(pos.z, self.max.z) + 1));
+        (0, remaining.and_then(|n| usize::try_from(n).ok()))
+    }
+}
+
+impl FusedIterator for IterSphere {}
+
 impl fmt::Debug for BlockPosition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("block")?;
@@ -631,6 +1179,42 @@ impl BlockOffset {
     pub const fn new(x: u8, y: u16, z: u8) -> Self {
         Self { x, z, y }
     }
+
+    /// Returns the adjacent block offset towards the given direction, in a chunk of the given
+    /// `height` (in blocks).
+    ///
+    /// Returns `None` if the neighbor is out of the world, i.e. below `0` or not below `height`.
+    /// Chunks are not stacked vertically (see [`ChunkPosition::offset`]), so only the horizontal
+    /// directions cross the chunk boundary.
+    ///
+    /// # Errors
+    ///
+    /// Returns the (horizontal) direction of the adjacent chunk and the wrapped offset in that
+    /// chunk if the neighbor crosses the chunk boundary.
+    pub const fn neighbor(
+        self,
+        dir: Direction,
+        height: u32,
+    ) -> Option<Result<Self, (Direction, Self)>> {
+        const MAX: u8 = CHUNK_WIDTH as u8 - 1;
+
+        let Self { x, y, z } = self;
+        let neighbor = match dir {
+            Direction::South if z == MAX => Err((dir, Self::new(x, y, 0))),
+            Direction::South => Ok(Self::new(x, y, z + 1)),
+            Direction::North if z == 0 => Err((dir, Self::new(x, y, MAX))),
+            Direction::North => Ok(Self::new(x, y, z - 1)),
+            Direction::East if x == MAX => Err((dir, Self::new(0, y, z))),
+            Direction::East => Ok(Self::new(x + 1, y, z)),
+            Direction::West if x == 0 => Err((dir, Self::new(MAX, y, z))),
+            Direction::West => Ok(Self::new(x - 1, y, z)),
+            Direction::Up if y as u32 + 1 >= height => return None,
+            Direction::Up => Ok(Self::new(x, y + 1, z)),
+            Direction::Down if y == 0 => return None,
+            Direction::Down => Ok(Self::new(x, y - 1, z)),
+        };
+        Some(neighbor)
+    }
 }
 
 impl fmt::Debug for BlockOffset {
@@ -647,6 +1231,163 @@ impl fmt::Display for BlockOffset {
     }
 }
 
+/// Returns an iterator over the blocks that the ray passes through, in order, starting with the
+/// block containing the `origin`.
+///
+/// The ray stops after `max_dist` blocks (measured along the ray, regardless of the length of
+/// `dir`). Only the block containing the `origin` is yielded if `dir` is zero or not finite.
+/// When the ray crosses an edge or a corner exactly, the blocks are entered one axis at a time
+/// (in the x-y-z order).
+///
+/// # Reference
+///
+/// - Amanatides, J., & Woo, A. (1987). A fast voxel traversal algorithm for ray tracing.
+///
+/// # Examples
+///
+/// ```
+/// # use cgmath::{Point3, Vector3};
+/// # use minecrium_common::coords::{voxel_traverse, BlockPosition};
+/// #
+/// let blocks: Vec<_> = voxel_traverse(Point3::new(0.5, 0.5, 0.5), Vector3::new(-1., 0., 0.), 2.).collect();
+/// assert_eq!(blocks, [
+///     BlockPosition::new(0, 0, 0),
+///     BlockPosition::new(-1, 0, 0),
+///     BlockPosition::new(-2, 0, 0),
+/// ]);
+/// ```
+pub fn voxel_traverse(
+    origin: Point3<f32>,
+    dir: Vector3<f32>,
+    max_dist: f32,
+) -> impl Iterator<Item = BlockPosition> {
+    VoxelTraverse::new(origin, dir, max_dist)
+}
+
+/// An iterator over the blocks that a ray passes through, see [`voxel_traverse`].
+#[derive(Clone, Debug)]
+struct VoxelTraverse {
+    /// the block to be yielded, or `None` if the iterator is exhausted.
+    pos: Option<BlockPosition>,
+    /// the step (`-1`, `0` or `1`) of each component.
+    step: [i32; 3],
+    /// the distance along the ray to the next block boundary of each component.
+    t_max: [f32; 3],
+    /// the distance along the ray between two block boundaries of each component.
+    t_delta: [f32; 3],
+    max_dist: f32,
+}
+
+impl VoxelTraverse {
+    fn new(origin: Point3<f32>, dir: Vector3<f32>, max_dist: f32) -> Self {
+        let pos = BlockPosition::new(
+            origin.x.floor() as i32,
+            origin.y.floor() as i32,
+            origin.z.floor() as i32,
+        );
+
+        let mut step = [0; 3];
+        let mut t_max = [f32::INFINITY; 3];
+        let mut t_delta = [f32::INFINITY; 3];
+
+        let dir = dir.normalize();
+        if dir.x.is_finite() && dir.y.is_finite() && dir.z.is_finite() {
+            for i in 0..3 {
+                let (o, d) = (origin[i], dir[i]);
+                if d > 0. {
+                    step[i] = 1;
+                    t_max[i] = (o.floor() + 1. - o) / d;
+                    t_delta[i] = 1. / d;
+                } else if d < 0. {
+                    step[i] = -1;
+                    t_max[i] = (o - o.floor()) / -d;
+                    t_delta[i] = -1. / d;
+                }
+            }
+        }
+
+        Self {
+            pos: (max_dist >= 0.).then_some(pos),
+            step,
+            t_max,
+            t_delta,
+            max_dist,
+        }
+    }
+}
+
+impl Iterator for VoxelTraverse {
+    type Item = BlockPosition;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos?;
+
+        // finds the nearest block boundary, and prepares the block behind it.
+        let [tx, ty, tz] = self.t_max;
+        let i = if tx <= ty && tx <= tz {
+            0
+        } else if ty <= tz {
+            1
+        } else {
+            2
+        };
+
+        // no boundary is reachable if none of the components steps, even if `max_dist` is infinite.
+        self.pos = if self.t_max[i].is_finite() && self.t_max[i] <= self.max_dist {
+            let mut next = pos;
+            match i {
+                0 => next.x += self.step[0],
+                1 => next.y += self.step[1],
+                _ => next.z += self.step[2],
+            }
+            self.t_max[i] += self.t_delta[i];
+            Some(next)
+        } else {
+            None
+        };
+
+        Some(pos)
+    }
+}
+
+impl FusedIterator for VoxelTraverse {}
+
+/// Samples an axis uniformly.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Axis> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Axis {
+        [Axis::X, Axis::Y, Axis::Z][rng.gen_range(0..3)]
+    }
+}
+
+/// Samples a horizontal axis uniformly.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<HAxis> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> HAxis {
+        if rng.gen() {
+            HAxis::X
+        } else {
+            HAxis::Z
+        }
+    }
+}
+
+/// Samples a direction uniformly from [`Direction::ALL`].
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Direction> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Direction {
+        Direction::ALL[rng.gen_range(0..Direction::ALL.len())]
+    }
+}
+
+/// Samples a horizontal direction uniformly from [`HDirection::ALL`].
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<HDirection> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> HDirection {
+        HDirection::ALL[rng.gen_range(0..HDirection::ALL.len())]
+    }
+}
+
 /// implements following traits for the positions:
 /// - `Index<_>`,           `IndexMut<_>`;
 /// - `AsRef<[i32]>`,       `AsMut<[i32]>`;
@@ -738,7 +1479,12 @@ impl_position!(ChunkPosition [2, HAxis] => {  x: X,       z: Z, });
 mod tests {
     use std::str::FromStr;
 
-    use crate::coords::{Axis, Direction};
+    use cgmath::Vector3;
+
+    use crate::coords::{
+        Axis, BlockOffset, BlockPosition, ChunkPosition, Direction, HAxis, HDirection,
+        RelativeDirection, DIRECTION_OFFSETS,
+    };
 
     const AXES: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
 
@@ -762,6 +1508,507 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_direction_faces_visible() {
+        assert_eq!(DIRECTIONS, Direction::ALL);
+
+        let visible: Vec<_> = Direction::faces_visible([false; 6]).collect();
+        assert_eq!(visible, DIRECTIONS);
+
+        let visible: Vec<_> = Direction::faces_visible([true; 6]).collect();
+        assert!(visible.is_empty());
+
+        let mask = [true, false, false, true, true, false];
+        let visible: Vec<_> = Direction::faces_visible(mask).collect();
+        assert_eq!(
+            visible,
+            [Direction::North, Direction::East, Direction::Down]
+        );
+    }
+
+    #[test]
+    fn test_direction_horizontal_vertical() {
+        let cardinals = [
+            HDirection::South,
+            HDirection::North,
+            HDirection::East,
+            HDirection::West,
+        ];
+
+        for (dir, hdir) in Direction::HORIZONTAL.into_iter().zip(cardinals) {
+            assert!(dir.is_horizontal());
+            assert_eq!(dir.as_ref(), hdir.as_ref());
+        }
+
+        for dir in Direction::VERTICAL {
+            assert!(!dir.is_horizontal());
+            assert_eq!(dir.axis(), Axis::Y);
+        }
+    }
+
+    #[test]
+    fn test_relative_direction_serde() {
+        let deserialized = r#"["front", "back", "left", "right", "up", "down"]"#;
+        let deserialized: [RelativeDirection; 6] = serde_json::from_str(deserialized).unwrap();
+        assert_eq!(RelativeDirection::ALL, deserialized);
+
+        for rel in RelativeDirection::ALL {
+            assert_eq!(rel, RelativeDirection::from_str(rel.as_ref()).unwrap());
+            assert_eq!(rel.to_string(), rel.as_ref());
+        }
+        assert!(RelativeDirection::from_str("south").is_err());
+    }
+
+    #[test]
+    fn test_direction_relative() {
+        use RelativeDirection::*;
+
+        for front in Direction::ALL {
+            let relatives = RelativeDirection::ALL.map(|rel| front.relative(rel));
+            assert_eq!(relatives[0], front);
+            for dir in Direction::ALL {
+                assert!(relatives.contains(&dir));
+            }
+
+            // the (right, up, back) directions form a right-handed basis.
+            let right = Vector3::<i32>::from(front.relative(Right));
+            let up = Vector3::<i32>::from(front.relative(Up));
+            let back = Vector3::<i32>::from(front.relative(Back));
+            assert_eq!(right.cross(up), back);
+            assert_eq!(front.relative(Left), front.relative(Right).opposite());
+            assert_eq!(front.relative(Down), front.relative(Up).opposite());
+        }
+
+        assert_eq!(Direction::South.relative(Right), Direction::West);
+        assert_eq!(Direction::East.relative(Left), Direction::North);
+        assert_eq!(Direction::Up.relative(Up), Direction::South);
+        assert_eq!(Direction::Down.relative(Up), Direction::North);
+        assert_eq!(Direction::Down.relative(Right), Direction::East);
+    }
+
+    #[test]
+    fn test_direction_dot() {
+        use cgmath::InnerSpace;
+
+        for lhs in Direction::ALL {
+            for rhs in Direction::ALL {
+                let dot = Vector3::<i32>::from(lhs).dot(Vector3::from(rhs));
+                assert_eq!(lhs.dot(rhs), dot, "{lhs} . {rhs}");
+                assert_eq!(lhs.is_opposite(rhs), dot == -1, "{lhs} . {rhs}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_direction_bits() {
+        for (bits, dir) in Direction::ALL.into_iter().enumerate() {
+            assert_eq!(dir.to_bits(), bits as u8);
+            assert_eq!(Direction::from_bits(bits as u8), Some(dir));
+        }
+        for bits in 6..=u8::MAX {
+            assert_eq!(Direction::from_bits(bits), None);
+        }
+
+        for (bits, dir) in HDirection::ALL.into_iter().enumerate() {
+            assert_eq!(dir.to_bits(), bits as u8);
+            assert_eq!(HDirection::from_bits(bits as u8), Some(dir));
+        }
+        for bits in 8..=u8::MAX {
+            assert_eq!(HDirection::from_bits(bits), None);
+        }
+
+        // packs a direction and a horizontal direction into one byte.
+        let packed = Direction::Up.to_bits() << 4 | HDirection::Northwest.to_bits();
+        assert_eq!(Direction::from_bits(packed >> 4), Some(Direction::Up));
+        assert_eq!(
+            HDirection::from_bits(packed & 0xf),
+            Some(HDirection::Northwest)
+        );
+    }
+
+    #[test]
+    fn test_direction_offsets() {
+        for (index, dir) in DIRECTIONS.into_iter().enumerate() {
+            let offset = Vector3::<i32>::from(dir);
+            assert_eq!(DIRECTION_OFFSETS[index], offset);
+            assert_eq!(offset, Vector3::<f32>::from(dir).cast().unwrap());
+            assert_eq!(offset, -Vector3::<i32>::from(dir.opposite()));
+        }
+    }
+
+    #[test]
+    fn test_block_position_long() {
+        // the example from <https://wiki.vg/Protocol#Position>.
+        let packed = 0x4607_632C_15B4_833F;
+        let pos = BlockPosition::new(18357644, 831, -20882616);
+        assert_eq!(pos.to_long(), packed);
+        assert_eq!(BlockPosition::from_long(packed), pos);
+
+        let positions = [
+            BlockPosition::new(0, 0, 0),
+            BlockPosition::new(-1, -1, -1),
+            BlockPosition::new(1, -64, -1),
+            BlockPosition::new(-(1 << 25), -(1 << 11), -(1 << 25)),
+            BlockPosition::new((1 << 25) - 1, (1 << 11) - 1, (1 << 25) - 1),
+        ];
+
+        for pos in positions {
+            assert_eq!(BlockPosition::from_long(pos.to_long()), pos);
+        }
+    }
+
+    #[test]
+    fn test_block_position_chunk_offset() {
+        let positions = [
+            BlockPosition::new(0, 0, 0),
+            BlockPosition::new(17, 64, -1),
+            BlockPosition::new(-16, 255, -17),
+            BlockPosition::new(-33, 3, 31),
+        ];
+
+        for pos in positions {
+            assert_eq!(pos.chunk_offset(), pos.into_parts().1);
+        }
+
+        let pos = BlockPosition::new(-1, 70, 17);
+        assert_eq!(pos.chunk_offset(), BlockOffset::new(15, 70, 1));
+    }
+
+    #[test]
+    fn test_block_position_iter_sphere() {
+        let center = BlockPosition::new(-5, 64, 12);
+        assert_eq!(BlockPosition::iter_sphere(center, -1.).count(), 0);
+        assert_eq!(BlockPosition::iter_sphere(center, f32::NAN).count(), 0);
+        assert_eq!(BlockPosition::iter_sphere(center, 0.5).count(), 1);
+        assert_eq!(BlockPosition::iter_sphere(center, 1.).count(), 7);
+        assert_eq!(BlockPosition::iter_sphere(center, 1.5).count(), 19);
+
+        let iter = BlockPosition::iter_sphere(center, 1.5);
+        assert_eq!(iter.size_hint(), (0, Some(27)));
+        for pos in iter {
+            let d = pos - center;
+            assert!(d.x * d.x + d.y * d.y + d.z * d.z <= 2);
+        }
+
+        // the corner `(-1, -1, -1)` is skipped, then `(-1, -1, 0)` is yielded.
+        let mut iter = BlockPosition::iter_sphere(center, 1.5);
+        assert_eq!(iter.next(), Some(center + Vector3::new(-1, -1, 0)));
+        assert_eq!(iter.size_hint(), (0, Some(25)));
+        assert_eq!(iter.by_ref().count(), 18);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        // the bounding cube is clipped to the range of `i32`.
+        let corner = BlockPosition::new(i32::MAX, i32::MIN, i32::MAX);
+        let iter = BlockPosition::iter_sphere(corner, 1.5);
+        assert_eq!(iter.size_hint(), (0, Some(8)));
+        assert_eq!(iter.count(), 7);
+
+        // huge and infinite radii.
+        let mut iter = BlockPosition::iter_sphere(BlockPosition::new(0, 0, 0), f32::INFINITY);
+        assert_eq!(
+            iter.next(),
+            Some(BlockPosition::new(i32::MIN, i32::MIN, i32::MIN))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(BlockPosition::new(i32::MIN, i32::MIN, i32::MIN + 1))
+        );
+        let center = BlockPosition::new(i32::MIN, i32::MIN, i32::MIN);
+        let mut iter = BlockPosition::iter_sphere(center, 3e9);
+        assert_eq!(iter.size_hint(), (0, None));
+        assert_eq!(iter.next(), Some(center));
+        assert_eq!(iter.next(), Some(center + Vector3::new(0, 0, 1)));
+    }
+
+    #[test]
+    fn test_block_offset_neighbor() {
+        let offset = BlockOffset::new(7, 64, 7);
+        let pos = BlockPosition::from_parts(ChunkPosition::new(0, 0), offset);
+        for dir in Direction::ALL {
+            let neighbor = offset.neighbor(dir, 256).unwrap().unwrap();
+            assert_eq!(
+                BlockPosition::from_parts(ChunkPosition::new(0, 0), neighbor),
+                pos + Vector3::from(dir)
+            );
+        }
+
+        let cases = [
+            (
+                BlockOffset::new(3, 64, 15),
+                Direction::South,
+                BlockOffset::new(3, 64, 0),
+            ),
+            (
+                BlockOffset::new(3, 64, 0),
+                Direction::North,
+                BlockOffset::new(3, 64, 15),
+            ),
+            (
+                BlockOffset::new(15, 64, 3),
+                Direction::East,
+                BlockOffset::new(0, 64, 3),
+            ),
+            (
+                BlockOffset::new(0, 64, 3),
+                Direction::West,
+                BlockOffset::new(15, 64, 3),
+            ),
+        ];
+        for (offset, dir, wrapped) in cases {
+            assert_eq!(offset.neighbor(dir, 256), Some(Err((dir, wrapped))));
+            assert_eq!(
+                wrapped.neighbor(dir.opposite(), 256),
+                Some(Err((dir.opposite(), offset)))
+            );
+        }
+
+        // the vertical neighbors never cross the chunk boundary, but leave the world.
+        let top = BlockOffset::new(3, 255, 3);
+        let bottom = BlockOffset::new(3, 0, 3);
+        assert_eq!(top.neighbor(Direction::Up, 256), None);
+        assert_eq!(bottom.neighbor(Direction::Down, 256), None);
+        assert_eq!(
+            BlockOffset::new(3, u16::MAX, 3).neighbor(Direction::Up, u16::MAX as u32 + 1),
+            None
+        );
+
+        // stays in the chunk at the opposite faces.
+        assert_eq!(
+            bottom.neighbor(Direction::Up, 256),
+            Some(Ok(BlockOffset::new(3, 1, 3)))
+        );
+        assert_eq!(
+            top.neighbor(Direction::Down, 256),
+            Some(Ok(BlockOffset::new(3, 254, 3)))
+        );
+    }
+
+    #[test]
+    fn test_voxel_traverse() {
+        use cgmath::Point3;
+
+        use crate::coords::voxel_traverse;
+
+        // a diagonal ray, which crosses the x boundaries first.
+        let origin = Point3::new(0.5, 0.25, 0.5);
+        let blocks: Vec<_> = voxel_traverse(origin, Vector3::new(1., 1., 0.), 3.).collect();
+        assert_eq!(
+            blocks,
+            [
+                BlockPosition::new(0, 0, 0),
+                BlockPosition::new(1, 0, 0),
+                BlockPosition::new(1, 1, 0),
+                BlockPosition::new(2, 1, 0),
+                BlockPosition::new(2, 2, 0),
+            ]
+        );
+
+        // a negative diagonal ray, the length of `dir` does not matter.
+        let origin = Point3::new(-0.5, 0.75, -0.5);
+        let blocks: Vec<_> = voxel_traverse(origin, Vector3::new(-5., -5., -5.), 1.).collect();
+        for pair in blocks.windows(2) {
+            let d = pair[1] - pair[0];
+            assert_eq!(d.x + d.y + d.z, -1);
+        }
+        assert_eq!(
+            blocks,
+            [
+                BlockPosition::new(-1, 0, -1),
+                BlockPosition::new(-2, 0, -1),
+                BlockPosition::new(-2, 0, -2),
+            ]
+        );
+
+        // axis-aligned rays.
+        for dir in Direction::ALL {
+            let origin = Point3::new(0.5, 63.5, 0.5);
+            let blocks: Vec<_> = voxel_traverse(origin, Vector3::from(dir), 2.).collect();
+            let start = BlockPosition::new(0, 63, 0);
+            let offset = Vector3::<i32>::from(dir);
+            assert_eq!(blocks, [start, start + offset, start + offset * 2]);
+        }
+
+        // degenerate rays.
+        let origin = Point3::new(0.5, 0.5, 0.5);
+        assert_eq!(
+            voxel_traverse(origin, Vector3::new(0., 0., 0.), 2.).count(),
+            1
+        );
+        assert_eq!(
+            voxel_traverse(origin, Vector3::new(0., 0., 0.), f32::INFINITY).count(),
+            1
+        );
+        assert_eq!(
+            voxel_traverse(origin, Vector3::new(f32::NAN, 0., 0.), f32::INFINITY).count(),
+            1
+        );
+        assert_eq!(
+            voxel_traverse(origin, Vector3::new(1., 0., 0.), 0.).count(),
+            1
+        );
+        assert_eq!(
+            voxel_traverse(origin, Vector3::new(1., 0., 0.), -1.).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_block_position_section() {
+        use cgmath::Point3;
+
+        assert_eq!(BlockPosition::new(0, 0, 0).section(), Point3::new(0, 0, 0));
+        assert_eq!(
+            BlockPosition::new(15, 15, 15).section(),
+            Point3::new(0, 0, 0)
+        );
+        assert_eq!(
+            BlockPosition::new(16, 64, 31).section(),
+            Point3::new(1, 4, 1)
+        );
+        assert_eq!(
+            BlockPosition::new(-1, -1, -1).section(),
+            Point3::new(-1, -1, -1)
+        );
+        assert_eq!(
+            BlockPosition::new(-16, -17, -33).section(),
+            Point3::new(-1, -2, -3)
+        );
+
+        let pos = BlockPosition::new(-20, -5, 37);
+        let section = pos.section();
+        assert_eq!(section.x, pos.into_parts().0.x);
+        assert_eq!(section.z, pos.into_parts().0.z);
+    }
+
+    #[test]
+    fn test_block_position_adjacency() {
+        const ADJACENCY: [(Direction, BlockPosition); 6] = BlockPosition::new(0, 0, 0).adjacency();
+
+        let pos = BlockPosition::new(-5, 64, 12);
+        for (i, (dir, neighbor)) in pos.adjacency().into_iter().enumerate() {
+            assert_eq!(dir, Direction::ALL[i]);
+            assert_eq!(neighbor, pos + Vector3::from(dir));
+            assert_eq!(Vector3::from(ADJACENCY[i].1), Vector3::from(dir));
+        }
+    }
+
+    #[test]
+    fn test_block_position_abs_signum() {
+        let pos = BlockPosition::new(-5, 0, 12);
+        assert_eq!(pos.abs(), BlockPosition::new(5, 0, 12));
+        assert_eq!(pos.signum(), BlockPosition::new(-1, 0, 1));
+    }
+
+    #[test]
+    fn test_chunk_position_offset() {
+        let pos = ChunkPosition::new(3, -7);
+        assert_eq!(pos.offset(Direction::Up, 1), None);
+        assert_eq!(pos.offset(Direction::Down, 1), None);
+
+        let neighbors = pos.neighbors();
+        for (dir, neighbor) in Direction::HORIZONTAL.into_iter().zip(neighbors) {
+            let offset = Vector3::<i32>::from(dir);
+            assert_eq!(neighbor, ChunkPosition::new(3 + offset.x, -7 + offset.z));
+            assert_eq!(pos.offset(dir, 1), Some(neighbor));
+            assert_eq!(pos.offset(dir, -1), pos.offset(dir.opposite(), 1));
+        }
+    }
+
+    #[test]
+    fn test_chunk_position_region() {
+        let cases = [
+            (ChunkPosition::new(0, 0), (0, 0), (0, 0)),
+            (ChunkPosition::new(31, 32), (0, 1), (31, 0)),
+            (ChunkPosition::new(-1, -32), (-1, -1), (31, 0)),
+            (ChunkPosition::new(-33, 65), (-2, 2), (31, 1)),
+        ];
+
+        for (pos, region, local) in cases {
+            assert_eq!(pos.region(), region, "{pos}");
+            assert_eq!(pos.region_local(), local, "{pos}");
+            assert_eq!(region.0 * 32 + local.0 as i32, pos.x);
+            assert_eq!(region.1 * 32 + local.1 as i32, pos.z);
+        }
+    }
+
+    #[test]
+    fn test_chunk_position_index() {
+        let mut pos = ChunkPosition::new(3, -7);
+        assert_eq!(pos[HAxis::X], 3);
+        assert_eq!(pos[HAxis::Z], -7);
+
+        pos[HAxis::X] += 1;
+        pos[HAxis::Z] -= 1;
+        assert_eq!(pos, ChunkPosition::new(4, -8));
+    }
+
+    #[test]
+    fn test_hdirection_angle() {
+        for dir in HDirection::ALL {
+            let angle = dir.angle_deg();
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let unit = dir.unit_vec();
+            assert!((unit.x + sin).abs() < 1e-6 && (unit.y - cos).abs() < 1e-6);
+            assert_eq!(HDirection::from_angle_deg(angle), dir);
+            assert_eq!(HDirection::from_angle_deg(angle + 20.), dir);
+            assert_eq!(HDirection::from_angle_deg(angle - 20.), dir);
+            assert_eq!(HDirection::from_angle_deg(angle - 360.), dir);
+            assert_eq!(HDirection::from_angle_deg(angle + 720.), dir);
+        }
+
+        assert_eq!(HDirection::from_angle_deg(23.), HDirection::Southwest);
+        assert_eq!(HDirection::from_angle_deg(-90.), HDirection::East);
+        assert_eq!(HDirection::from_angle_deg(359.), HDirection::South);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_distribution() {
+        use rand::{Rng, SeedableRng};
+
+        /// Samples `n` values per variant, and checks each variant is sampled about `n` times.
+        fn check<T: PartialEq + std::fmt::Debug>(values: &[T], rng: &mut impl Rng)
+        where
+            rand::distributions::Standard: rand::distributions::Distribution<T>,
+        {
+            const N: usize = 1000;
+
+            let mut counts = vec![0; values.len()];
+            for _ in 0..N * values.len() {
+                let value: T = rng.gen();
+                counts[values.iter().position(|v| *v == value).unwrap()] += 1;
+            }
+            for (value, count) in values.iter().zip(counts) {
+                assert!(
+                    (N * 4 / 5..N * 6 / 5).contains(&count),
+                    "{value:?}: {count}"
+                );
+            }
+        }
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x6d69_6e65);
+        check(&AXES, &mut rng);
+        check(&[HAxis::X, HAxis::Z], &mut rng);
+        check(&Direction::ALL, &mut rng);
+        check(&HDirection::ALL, &mut rng);
+    }
+
+    #[test]
+    fn test_axis_cycle_index() {
+        assert_eq!(Axis::X.cycle(), Axis::Y);
+        assert_eq!(Axis::Y.cycle(), Axis::Z);
+        assert_eq!(Axis::Z.cycle(), Axis::X);
+
+        assert_eq!(Axis::Z.index(), 0);
+        assert_eq!(Axis::X.index(), 1);
+        assert_eq!(Axis::Y.index(), 2);
+
+        for axis in AXES {
+            assert_eq!(Axis::from_index(axis.index()), Some(axis));
+        }
+        assert_eq!(Axis::from_index(3), None);
+    }
+
     #[test]
     fn test_axis_serde() {
         let deserialized = r#"["x", "y", "z"]"#;
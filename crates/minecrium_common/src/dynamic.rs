@@ -1,4 +1,5 @@
 use std::any::{self, Any};
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -13,6 +14,35 @@ pub trait AsAny: Any {
     /// Returns the [`type name`](any::type_name) as a string slice.
     fn type_name(&self) -> &'static str;
 
+    /// Returns the last path segment of the [`type name`](any::type_name).
+    ///
+    /// e.g. `minecrium_common::coords::Direction` is shortened to `Direction`. Generic arguments
+    /// are kept as they are, and the names of arrays, slices, tuples, references and pointers are
+    /// not shortened at all.
+    fn type_name_short(&self) -> &'static str {
+        let name = self.type_name();
+
+        if !name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            return name;
+        }
+
+        let end = name.find('<').unwrap_or(name.len());
+        match name[..end].rfind("::") {
+            Some(start) => &name[start + 2..],
+            None => name,
+        }
+    }
+
+    /// Returns `true` if `other` is of the same type as `self`.
+    ///
+    /// The types are compared by their [`TypeId`](any::TypeId)s, which are unique unlike the
+    /// type names. Note that a `Box<dyn AsAny>` is also `AsAny` itself, so the boxed value should
+    /// be passed as `&*boxed` rather than `&boxed`.
+    #[inline]
+    fn same_type_as(&self, other: &dyn AsAny) -> bool {
+        self.as_any().type_id() == other.as_any().type_id()
+    }
+
     /// Returns the value as [`&dyn Any`](Any).
     fn as_any(&self) -> &dyn Any;
 
@@ -45,6 +75,40 @@ pub trait AsAnySync: AsAny + Send + Sync {
     fn into_any_sync_arc(self: Arc<Self>) -> Arc<dyn Any + Send + Sync>;
 }
 
+/// Compares the trait object with a value of any type.
+///
+/// This trait is automatically implemented for types that implemenet the [`Any`] and
+/// [`PartialEq`] traits.
+pub trait DynEq: AsAny {
+    /// Returns `true` if `other` is of the same type as `self` and they are equal.
+    ///
+    /// Like [`AsAny::same_type_as`], the boxed value should be passed as `&*boxed` rather than
+    /// `&boxed`.
+    fn dyn_eq(&self, other: &dyn AsAny) -> bool;
+}
+
+/// Feeds the trait object into a [`Hasher`].
+///
+/// This trait is automatically implemented for types that implemenet the [`Any`] and [`Hash`]
+/// traits. The hash is the same as the one produced by [`Hash::hash`] of the inner type.
+pub trait DynHash: AsAny {
+    /// Feeds the value into the given hasher.
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+/// Clones the trait object.
+///
+/// This trait is automatically implemented for types that implemenet the [`Any`] and [`Clone`]
+/// traits, and `Box<dyn DynClone>` implements [`Clone`] itself.
+#[downcast(crate = crate)]
+pub trait DynClone: AsAny {
+    /// Returns a boxed copy of the value.
+    ///
+    /// Note that a `Box<dyn DynClone>` is also `DynClone` itself, so the boxed value should be
+    /// cloned by `(*boxed).dyn_clone()` or `boxed.clone()` rather than `boxed.dyn_clone()`.
+    fn dyn_clone(&self) -> Box<dyn DynClone>;
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 //                                      TRAIT IMPLEMENTATION                                      //
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -98,10 +162,126 @@ impl<T: Any + Send + Sync> AsAnySync for T {
     }
 }
 
+impl<T: Any + PartialEq> DynEq for T {
+    #[inline]
+    fn dyn_eq(&self, other: &dyn AsAny) -> bool {
+        match other.as_any().downcast_ref::<T>() {
+            Some(other) => self == other,
+            None => false,
+        }
+    }
+}
+
+impl<T: Any + Hash> DynHash for T {
+    #[inline]
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state)
+    }
+}
+
+impl<T: Any + Clone> DynClone for T {
+    #[inline]
+    fn dyn_clone(&self) -> Box<dyn DynClone> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynClone> {
+    #[inline]
+    fn clone(&self) -> Self {
+        // clones the inner value, since `Box<dyn DynClone>` is also `DynClone` itself.
+        (**self).dyn_clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::dynamic::*;
 
+    use std::collections::hash_map::DefaultHasher;
+
+    struct Foo;
+
+    #[test]
+    fn test_type_name_short() {
+        assert_eq!(Foo.type_name(), "minecrium_common::dynamic::tests::Foo");
+        assert_eq!(Foo.type_name_short(), "Foo");
+
+        let val: &dyn AsAnySync = &Foo;
+        assert_eq!(val.type_name_short(), "Foo");
+
+        assert_eq!(32_i32.type_name_short(), "i32");
+        assert_eq!(
+            vec![Foo].type_name_short(),
+            "Vec<minecrium_common::dynamic::tests::Foo>"
+        );
+        assert_eq!([0_u8; 4].type_name_short(), "[u8; 4]");
+    }
+
+    #[test]
+    fn test_same_type_as() {
+        let val: &dyn AsAny = &32_i32;
+        assert!(val.same_type_as(&64_i32));
+        assert!(!val.same_type_as(&64_u32));
+        assert!(!val.same_type_as(&Foo));
+
+        let boxed: Box<dyn AsAny> = Box::new(64_i32);
+        assert!(val.same_type_as(&*boxed));
+        assert!(!val.same_type_as(&boxed));
+    }
+
+    #[test]
+    fn test_dyn_eq() {
+        let val: &dyn DynEq = &32_i32;
+        assert!(val.dyn_eq(&32_i32));
+        assert!(!val.dyn_eq(&64_i32));
+        assert!(!val.dyn_eq(&32_i64));
+
+        let other: Box<dyn AsAny> = Box::new(32_i32);
+        assert!(val.dyn_eq(&*other));
+        assert!(!val.dyn_eq(&other));
+    }
+
+    #[test]
+    fn test_dyn_hash() {
+        fn hash(value: &dyn DynHash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.dyn_hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let val: Box<dyn DynHash> = Box::new(String::from("minecrium"));
+        assert_eq!(hash(val.as_ref()), hash(val.as_ref()));
+        assert_eq!(hash(val.as_ref()), hash(&String::from("minecrium")));
+        assert_ne!(hash(val.as_ref()), hash(&String::from("minecraft")));
+
+        let mut hasher = DefaultHasher::new();
+        "minecrium".hash(&mut hasher);
+        assert_eq!(hash(val.as_ref()), hasher.finish());
+    }
+
+    #[test]
+    fn test_dyn_clone() {
+        let val: Box<dyn DynClone> = Box::new(vec![1_i32, 2, 3]);
+        let cloned = (*val).dyn_clone();
+        assert_eq!(cloned.downcast_ref::<Vec<i32>>(), Some(&vec![1, 2, 3]));
+
+        // the clone is still `DynClone`, so it can be cloned again.
+        let cloned = (*cloned).dyn_clone().clone();
+        assert!(cloned.is::<Vec<i32>>());
+        assert_eq!(*cloned.downcast::<Vec<i32>>().ok().unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_downcast_ref() {
+        let val: &dyn AsAnySync = &32_i32;
+        assert_eq!(val.try_downcast_ref::<i32>().ok(), Some(&32_i32));
+
+        let err = val.try_downcast_ref::<char>().unwrap_err();
+        assert_eq!(err.src, "i32");
+        assert_eq!(err.dst, "char");
+    }
+
     #[test]
     fn test_downcast_sync() {
         let val: &dyn AsAnySync = &32_i32;
@@ -124,4 +304,17 @@ mod tests {
         assert_eq!(*val.downcast_ref::<i32>().unwrap(), 64_i32);
         assert_eq!(*val.downcast_arc::<i32>().ok().unwrap(), 64_i32);
     }
+
+    #[test]
+    fn test_downcast_boxed() {
+        let val: Box<dyn AsAnySync> = Box::new(32_i32);
+        let val = val.downcast_boxed::<i32>();
+        let val = std::thread::spawn(move || *val.unwrap()).join().unwrap();
+        assert_eq!(val, 32_i32);
+
+        let val: Box<dyn AsAnySync> = Box::new(32_i32);
+        let err = val.downcast_boxed::<char>().unwrap_err();
+        let err = std::thread::spawn(move || err.downcast::<i32>().ok().map(|val| *val));
+        assert_eq!(err.join().unwrap(), Some(32_i32));
+    }
 }
@@ -51,17 +51,42 @@ impl fmt::Display for ParseHDirectionError {
 
 impl StdError for ParseHDirectionError {}
 
+/// An error that is [`<RelativeDirection as FromStr>::Err`](std::str::FromStr::Err).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParseRelativeDirectionError;
+
+impl fmt::Display for ParseRelativeDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(r#"expects one of "front", "back", "left", "right", "up", "down"."#)
+    }
+}
+
+impl StdError for ParseRelativeDirectionError {}
+
 /// An error type for [`resource locations`](crate::resource::ResLocation).
+///
+/// This enum is non-exhaustive, more validation errors may be added in the future.
+#[non_exhaustive]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ResLocationError {
     /// Resource location has an empty namespace.
     NamespaceEmpty,
     /// The namespace has non [a-z0-9_.-] character.
-    NamespaceError,
+    NamespaceError {
+        /// the first invalid character.
+        ch: char,
+        /// the byte index of the first invalid character in the namespace.
+        pos: usize,
+    },
     /// Resource location has an empty path.
     PathEmpty,
     /// The path has non [a-z0-9_.-/] character.
-    PathError,
+    PathError {
+        /// the first invalid character.
+        ch: char,
+        /// the byte index of the first invalid character in the path.
+        pos: usize,
+    },
 }
 
 impl ResLocationError {
@@ -72,14 +97,14 @@ impl ResLocationError {
     /// - non-empty, ascii-only, matches pattern `[a-z0-9_.-]+`
     pub fn check_namespace(namespace: &str) -> Result<(), Self> {
         #[inline(always)]
-        fn is_valid_char(c: &u8) -> bool {
-            matches!(c, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'.')
+        fn is_valid_char(c: char) -> bool {
+            matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.')
         }
 
         if namespace.is_empty() {
             Err(Self::NamespaceEmpty)
-        } else if !namespace.as_bytes().iter().all(is_valid_char) {
-            Err(Self::NamespaceError)
+        } else if let Some((pos, ch)) = namespace.char_indices().find(|(_, c)| !is_valid_char(*c)) {
+            Err(Self::NamespaceError { ch, pos })
         } else {
             Ok(())
         }
@@ -92,14 +117,14 @@ impl ResLocationError {
     /// - non-empty, ascii-only, matches pattern `[a-z0-9_.-/]+`
     pub fn check_path(path: &str) -> Result<(), Self> {
         #[inline(always)]
-        fn is_valid_char(c: &u8) -> bool {
-            matches!(c, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'.' | b'/')
+        fn is_valid_char(c: char) -> bool {
+            matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | '/')
         }
 
         if path.is_empty() {
             Err(Self::PathEmpty)
-        } else if !path.as_bytes().iter().all(is_valid_char) {
-            Err(Self::PathError)
+        } else if let Some((pos, ch)) = path.char_indices().find(|(_, c)| !is_valid_char(*c)) {
+            Err(Self::PathError { ch, pos })
         } else {
             Ok(())
         }
@@ -108,17 +133,57 @@ impl ResLocationError {
 
 impl fmt::Display for ResLocationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Self::NamespaceEmpty => "the resource location namespace is empty",
-            Self::NamespaceError => "the resource location namespace has non [a-z0-9_.-] char",
-            Self::PathEmpty => "the resource location path is empty",
-            Self::PathError => "the resource location path has non [a-z0-9_.-/] char",
-        })
+        match self {
+            Self::NamespaceEmpty => f.write_str("the resource location namespace is empty"),
+            Self::NamespaceError { ch, pos } => write!(
+                f,
+                "the resource location namespace has non [a-z0-9_.-] char {ch:?} at {pos}"
+            ),
+            Self::PathEmpty => f.write_str("the resource location path is empty"),
+            Self::PathError { ch, pos } => write!(
+                f,
+                "the resource location path has non [a-z0-9_.-/] char {ch:?} at {pos}"
+            ),
+        }
     }
 }
 
 impl StdError for ResLocationError {}
 
+/// An error type for [`ResLocation::new_unreserved`](crate::resource::ResLocation::new_unreserved).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReservedNamespaceError {
+    /// The namespace is reserved.
+    Reserved,
+    /// The resource location is invalid.
+    Invalid(ResLocationError),
+}
+
+impl From<ResLocationError> for ReservedNamespaceError {
+    #[inline]
+    fn from(value: ResLocationError) -> Self {
+        Self::Invalid(value)
+    }
+}
+
+impl fmt::Display for ReservedNamespaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reserved => f.write_str("the resource location namespace is reserved"),
+            Self::Invalid(err) => <ResLocationError as fmt::Display>::fmt(err, f),
+        }
+    }
+}
+
+impl StdError for ReservedNamespaceError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Reserved => None,
+            Self::Invalid(err) => Some(err),
+        }
+    }
+}
+
 /// An error returned when failing to downcast a trait object to a specific type.
 #[derive(Clone, Debug)]
 pub struct DowncastError {
@@ -136,6 +201,14 @@ impl DowncastError {
             dst: std::any::type_name::<Dst>(),
         }
     }
+
+    /// Returns a value cast error from the type `Src` to the type `Dst`.
+    pub fn between<Src: ?Sized, Dst: ?Sized>() -> Self {
+        Self {
+            src: std::any::type_name::<Src>(),
+            dst: std::any::type_name::<Dst>(),
+        }
+    }
 }
 
 impl fmt::Display for DowncastError {
@@ -146,3 +219,79 @@ impl fmt::Display for DowncastError {
 }
 
 impl StdError for DowncastError {}
+
+/// An error type for [`Registry::try_reserve`](crate::resource::Registry::try_reserve).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// Failed to reserve the store or the resource locations.
+    Store(std::collections::TryReserveError),
+    /// Failed to reserve the hash map index.
+    Index(bevy_utils::hashbrown::TryReserveError),
+}
+
+impl From<std::collections::TryReserveError> for TryReserveError {
+    #[inline]
+    fn from(value: std::collections::TryReserveError) -> Self {
+        Self::Store(value)
+    }
+}
+
+impl From<bevy_utils::hashbrown::TryReserveError> for TryReserveError {
+    #[inline]
+    fn from(value: bevy_utils::hashbrown::TryReserveError) -> Self {
+        Self::Index(value)
+    }
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use bevy_utils::hashbrown::TryReserveError as IndexError;
+
+        match self {
+            Self::Store(err) => <std::collections::TryReserveError as fmt::Display>::fmt(err, f),
+            Self::Index(IndexError::CapacityOverflow) => {
+                f.write_str("memory allocation failed because the computed capacity exceeded the registry index's maximum")
+            }
+            Self::Index(IndexError::AllocError { layout }) => {
+                write!(f, "memory allocation of {} bytes failed for the registry index", layout.size())
+            }
+        }
+    }
+}
+
+impl StdError for TryReserveError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Store(err) => Some(err),
+            // the hashbrown error does not implement the `Error` trait.
+            Self::Index(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::*;
+
+    #[test]
+    fn test_downcast_error() {
+        fn cast() -> Result<(), Box<dyn StdError>> {
+            Err(DowncastError::between::<str, i32>())?
+        }
+
+        let err = cast().unwrap_err();
+        assert_eq!(err.to_string(), "try to cast `str` to `i32`");
+        assert!(err.is::<DowncastError>());
+    }
+
+    #[test]
+    fn test_try_reserve_error() {
+        let err = TryReserveError::from(bevy_utils::hashbrown::TryReserveError::CapacityOverflow);
+        assert!(err.to_string().contains("registry index"));
+        assert!(err.source().is_none());
+
+        let err = TryReserveError::from(Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err());
+        assert!(matches!(err, TryReserveError::Store(_)));
+        assert!(err.source().is_some());
+    }
+}
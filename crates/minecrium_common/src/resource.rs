@@ -6,6 +6,7 @@
 //! | ------------------------ | ----------------------------------------------------------------- |
 //! | [`Registry`]             | A collection to manage resources.                                 |
 //! | [`ResKey`]               | An index to the registry.                                         |
+//! | [`ErasedResKey`]         | A type-erased [`ResKey`].                                         |
 //! | [`ResLocation`]          | A unique identifier for resources.                                |
 //!
 //! # Reference
@@ -18,13 +19,14 @@ use std::hash::{BuildHasher, Hash, Hasher};
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use bevy_utils::HashMap;
 use serde::{Deserialize, Serialize};
 
-use crate::errors::ResLocationError;
+use crate::errors::{ResLocationError, ReservedNamespaceError, TryReserveError};
 
 /// `ResLocation` (short for *resource location*) is a unique identifier to identifies
 /// resources.
@@ -34,7 +36,7 @@ use crate::errors::ResLocationError;
 /// is a context-specified path fragment.
 ///
 /// - both of the `nampespace` and `path` are required to be *non-empty* and
-/// *ascii-only*
+///   *ascii-only*
 ///
 /// - the `namespace` is required to match the pattern `[a-z0-9_.-]+`.
 ///
@@ -114,6 +116,88 @@ impl ResLocation {
         Ok(unsafe { Self::new_unchecked(namespace, path) })
     }
 
+    /// Returns an resource location from the given namespace and path, where the namespace is not
+    /// one of the `reserved` namespaces.
+    ///
+    /// This method is intended for mod-facing registration, e.g. to prevent mods from registering
+    /// resources under `"minecraft"` or `"minecrium"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the namespace or path is invalid, or the namespace is reserved.
+    pub fn new_unreserved(
+        namespace: &str,
+        path: &str,
+        reserved: &[&str],
+    ) -> Result<Self, ReservedNamespaceError> {
+        let location = Self::new(namespace, path)?;
+
+        if reserved.contains(&namespace) {
+            Err(ReservedNamespaceError::Reserved)
+        } else {
+            Ok(location)
+        }
+    }
+
+    /// Returns an resource location from the given namespace and path, coercing invalid characters
+    /// instead of failing. Returns `true` in `.1` if any change was made.
+    ///
+    /// The namespace and path are transformed by the following rules:
+    ///
+    /// - the ascii uppercase letters are converted to lowercase, e.g. `'A'` to `'a'`.
+    /// - every other invalid character (including non-ascii characters, and `'/'` in the
+    ///   namespace) is replaced with `'_'`.
+    /// - an empty namespace is replaced with the default namespace (`"minecrium"`).
+    /// - an empty path is replaced with `"_"`.
+    ///
+    /// Valid namespaces and paths are left unchanged, so this method returns the same resource
+    /// location as [`ResLocation::new`] and `false` for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use minecrium_common::resource::ResLocation;
+    /// #
+    /// let (loc, changed) = ResLocation::new_lossy("MyMod", "Blocks/Oak Log");
+    /// assert_eq!(loc.as_str(), "mymod:blocks/oak_log");
+    /// assert!(changed);
+    /// ```
+    pub fn new_lossy(namespace: &str, path: &str) -> (Self, bool) {
+        fn coerce(s: &str, is_path: bool, changed: &mut bool) -> String {
+            s.chars()
+                .map(|c| match c {
+                    'a'..='z' | '0'..='9' | '_' | '-' | '.' => c,
+                    '/' if is_path => c,
+                    'A'..='Z' => {
+                        *changed = true;
+                        c.to_ascii_lowercase()
+                    }
+                    _ => {
+                        *changed = true;
+                        '_'
+                    }
+                })
+                .collect()
+        }
+
+        let mut changed = false;
+        let namespace = if namespace.is_empty() {
+            changed = true;
+            Self::DEFAULT_NAMESPACE.to_owned()
+        } else {
+            coerce(namespace, false, &mut changed)
+        };
+        let path = if path.is_empty() {
+            changed = true;
+            "_".to_owned()
+        } else {
+            coerce(path, true, &mut changed)
+        };
+
+        // SAFETY: all invalid characters are just replaced, and both parts are non-empty.
+        (unsafe { Self::new_unchecked(&namespace, &path) }, changed)
+    }
+
     /// Returns an resource location from the given path and the default namespace (`"minecrium"`).
     ///
     /// The path is required to be **non-empty**, **ascii-only** and should match the pattern
@@ -184,6 +268,72 @@ impl ResLocation {
         let inner = self.inner.as_ref();
         &inner.location[inner.delimiter + 1..]
     }
+
+    /// Returns `true` if the resource location is the same as the string slice.
+    ///
+    /// The string slice is compared with the format `"{namespace}:{path}"`, without constructing
+    /// a resource location.
+    #[inline]
+    pub fn eq_str(&self, s: &str) -> bool {
+        self.as_str() == s
+    }
+
+    /// Returns the relative file path `assets/<namespace>/<ctx>/<path>` of the resource, with the
+    /// `extension` appended to the last path segment.
+    ///
+    /// The `ctx` may consist of several segments separated by `'/'`, e.g. `"textures/block"`.
+    ///
+    /// Returns `None` if the `ctx` or path has an empty, `"."` or `".."` segment, the `ctx` has a
+    /// `'\\'` or `':'`, or the `extension` is empty or has a `'/'`, `'\\'` or `':'`, as such
+    /// paths may escape the namespace directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// # use std::str::FromStr;
+    /// # use minecrium_common::resource::ResLocation;
+    /// #
+    /// let loc = ResLocation::from_str("minecraft:block/stone").unwrap();
+    /// assert_eq!(
+    ///     loc.to_asset_path("models", Some("json")).unwrap(),
+    ///     Path::new("assets/minecraft/models/block/stone.json"),
+    /// );
+    /// assert_eq!(loc.to_asset_path("../models", None), None);
+    /// ```
+    pub fn to_asset_path(&self, ctx: &str, extension: Option<&str>) -> Option<PathBuf> {
+        fn is_valid_segment(segment: &str) -> bool {
+            !matches!(segment, "" | "." | "..") && !segment.contains(['\\', ':'])
+        }
+
+        let (namespace, path) = self.as_parts();
+        let ctx_segments = ctx.split('/');
+        let path_segments = path.split('/');
+        if !ctx_segments
+            .clone()
+            .chain(path_segments.clone())
+            .all(is_valid_segment)
+        {
+            return None;
+        }
+
+        let mut buf = PathBuf::from("assets");
+        buf.push(namespace);
+        buf.extend(ctx_segments);
+        buf.extend(path_segments);
+
+        if let Some(extension) = extension {
+            if extension.is_empty() || extension.contains(['/', '\\', ':']) {
+                return None;
+            }
+            let mut file_name = buf.file_name()?.to_os_string();
+            file_name.push(".");
+            file_name.push(extension);
+            buf.set_file_name(file_name);
+        }
+
+        Some(buf)
+    }
 }
 
 impl PartialEq for ResLocation {
@@ -196,6 +346,52 @@ impl PartialEq for ResLocation {
 
 impl Eq for ResLocation {}
 
+impl PartialOrd for ResLocation {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ResLocation {
+    /// Compares the namespaces first, then the paths, by their string contents.
+    ///
+    /// Note that this is not the same as comparing the whole strings, e.g. `"a:z"` is less than
+    /// `"a-b:a"`, while the string `"a-b:a"` is less than `"a:z"` since `'-'` is less than `':'`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_parts().cmp(&other.as_parts())
+    }
+}
+
+impl PartialEq<str> for ResLocation {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.eq_str(other)
+    }
+}
+
+impl PartialEq<&str> for ResLocation {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_str(other)
+    }
+}
+
+impl PartialEq<ResLocation> for str {
+    #[inline]
+    fn eq(&self, other: &ResLocation) -> bool {
+        other.eq_str(self)
+    }
+}
+
+impl PartialEq<ResLocation> for &str {
+    #[inline]
+    fn eq(&self, other: &ResLocation) -> bool {
+        other.eq_str(self)
+    }
+}
+
 impl Hash for ResLocation {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -311,80 +507,79 @@ impl<'de> Deserialize<'de> for ResLocation {
 /// A specialized index to the [`Registry<T>`].
 ///
 /// [`Registry<T>`] is randomly accessile by [`ResKey<T>`].
-pub struct ResKey<T> {
+///
+/// The `Tag` is the same as the tag of the registry, see [`Registry::new_tagged`].
+pub struct ResKey<T, Tag = ()> {
     index: u32,
-    marker: PhantomData<T>,
+    marker: PhantomData<(T, fn() -> Tag)>,
 }
 
-impl<T> Clone for ResKey<T> {
+impl<T, Tag> Clone for ResKey<T, Tag> {
     #[inline]
     fn clone(&self) -> Self {
-        Self {
-            index: self.index,
-            marker: PhantomData,
-        }
+        *self
     }
 }
 
-impl<T> Copy for ResKey<T> {}
+impl<T, Tag> Copy for ResKey<T, Tag> {}
 
-impl<T> PartialEq for ResKey<T> {
+impl<T, Tag> PartialEq for ResKey<T, Tag> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.index == other.index
     }
 }
 
-impl<T> Eq for ResKey<T> {}
+impl<T, Tag> Eq for ResKey<T, Tag> {}
 
-impl<T> PartialOrd for ResKey<T> {
+impl<T, Tag> PartialOrd for ResKey<T, Tag> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for ResKey<T> {
+impl<T, Tag> Ord for ResKey<T, Tag> {
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.index.cmp(&other.index)
     }
 }
 
-impl<T> Hash for ResKey<T> {
+impl<T, Tag> Hash for ResKey<T, Tag> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.index.hash(state);
     }
 }
 
-impl<T> fmt::Debug for ResKey<T> {
+impl<T, Tag> fmt::Debug for ResKey<T, Tag> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         <u32 as fmt::Debug>::fmt(&self.index, f)
     }
 }
 
-impl<T> fmt::Display for ResKey<T> {
+impl<T, Tag> fmt::Display for ResKey<T, Tag> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         <u32 as fmt::Display>::fmt(&self.index, f)
     }
 }
 
-impl<T> From<ResKey<T>> for u32 {
+impl<T, Tag> From<ResKey<T, Tag>> for u32 {
     #[inline]
-    fn from(value: ResKey<T>) -> Self {
+    fn from(value: ResKey<T, Tag>) -> Self {
         value.index
     }
 }
 
-impl<T> From<ResKey<T>> for usize {
+impl<T, Tag> From<ResKey<T, Tag>> for usize {
     #[inline]
-    fn from(value: ResKey<T>) -> Self {
+    fn from(value: ResKey<T, Tag>) -> Self {
         value.index as usize
     }
 }
 
-impl<T> From<u32> for ResKey<T> {
+impl<T, Tag> From<u32> for ResKey<T, Tag> {
     #[inline]
     fn from(value: u32) -> Self {
         Self {
@@ -394,41 +589,151 @@ impl<T> From<u32> for ResKey<T> {
     }
 }
 
+impl<T, Tag> ResKey<T, Tag> {
+    /// Returns the resource key with the type erased.
+    #[inline]
+    pub const fn erase(self) -> ErasedResKey {
+        ErasedResKey { index: self.index }
+    }
+}
+
+/// A [`ResKey<T>`] with the type `T` erased.
+///
+/// The erased resource key is useful to index heterogeneous registries (e.g. as the key of a
+/// `HashMap<ErasedResKey, Box<dyn Any>>`), and can be re-typed by [`ErasedResKey::typed`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErasedResKey {
+    index: u32,
+}
+
+impl ErasedResKey {
+    /// Returns the resource key of the type `U`.
+    ///
+    /// The resource key is just an index, so re-typing it to a wrong type is a logic error, but
+    /// never an undefined behavior.
+    #[inline]
+    pub const fn typed<U>(self) -> ResKey<U> {
+        self.typed_tagged()
+    }
+
+    /// Returns the resource key of the type `U` and the tag `Tag`, see [`ErasedResKey::typed`].
+    #[inline]
+    pub const fn typed_tagged<U, Tag>(self) -> ResKey<U, Tag> {
+        ResKey {
+            index: self.index,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl fmt::Debug for ErasedResKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <u32 as fmt::Debug>::fmt(&self.index, f)
+    }
+}
+
+impl fmt::Display for ErasedResKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <u32 as fmt::Display>::fmt(&self.index, f)
+    }
+}
+
+impl<T, Tag> From<ResKey<T, Tag>> for ErasedResKey {
+    #[inline]
+    fn from(value: ResKey<T, Tag>) -> Self {
+        value.erase()
+    }
+}
+
 /// A specialized hash map with the keys of [`ResLocation`] and the values of `T`.
 ///
 /// The container also provides random access with the index of [`ResKey<T>`].
-pub struct Registry<T> {
+///
+/// # Key stability
+///
+/// The resource keys are dense: the `n`-th inserted element has the resource key `n`, which is
+/// its index in the store, and the resource key never changes as long as the element is in the
+/// registry. Only [`Registry::clear`] invalidates the resource keys. See
+/// [`Registry::assert_dense`].
+///
+/// # Tags
+///
+/// The resource keys of two registries with the same value type are interchangeable by default.
+/// A registry created by [`Registry::new_tagged`] carries a zero-sized `Tag` in its type and the
+/// type of its resource keys, so that the keys of different tagged registries don't unify.
+pub struct Registry<T, Tag = ()> {
     /// Maps resource key to resource value.
     store: Vec<T>,
     /// Maps resource location to resource key.
     index: HashMap<ResLocation, u32>,
+    /// Maps resource key to resource location.
+    locations: Vec<ResLocation>,
+    /// the tag is never stored, so it does not affect the auto traits of the registry.
+    marker: PhantomData<fn() -> Tag>,
 }
 
 impl<T> Registry<T> {
     /// Returns an empty registry.
     #[inline]
     pub fn new() -> Self {
-        Self {
-            store: Vec::new(),
-            index: HashMap::default(),
-        }
+        Self::default()
     }
 
     /// Returns an empty registry with at least the specified capacity.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
+        Self::with_capacity_tagged(capacity)
+    }
+
+    /// Returns an empty registry with the tag `Tag`, whose resource keys are `ResKey<T, Tag>`.
+    ///
+    /// # Examples
+    ///
+    /// ```compile_fail
+    /// # use std::str::FromStr;
+    /// # use minecrium_common::resource::{Registry, ResLocation};
+    /// #
+    /// struct Items;
+    /// struct Blocks;
+    ///
+    /// let mut items = Registry::<u32>::new_tagged::<Items>();
+    /// let blocks = Registry::<u32>::new_tagged::<Blocks>();
+    ///
+    /// let stone = items.insert(ResLocation::from_str("stone").unwrap(), 1).unwrap();
+    /// blocks.get(stone); // mismatched types
+    /// ```
+    #[inline]
+    pub fn new_tagged<Tag>() -> Registry<T, Tag> {
+        Registry::default()
+    }
+
+    /// Returns an empty registry with the tag `Tag` and at least the specified capacity.
+    ///
+    /// See [`Registry::new_tagged`] for more details.
+    #[inline]
+    pub fn with_capacity_tagged<Tag>(capacity: usize) -> Registry<T, Tag> {
+        Registry {
             store: Vec::with_capacity(capacity),
             index: HashMap::with_capacity(capacity),
+            locations: Vec::with_capacity(capacity),
+            marker: PhantomData,
         }
     }
+}
 
+impl<T, Tag> Registry<T, Tag> {
     /// Returns the number of elements in the registry.
     #[inline]
     pub fn len(&self) -> usize {
         self.store.len()
     }
 
+    /// Returns the number of elements the registry can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.store.capacity()
+    }
+
     /// Returns `true` if the registry contains no element.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -444,10 +749,39 @@ impl<T> Registry<T> {
     /// Returns an unordered iterator over resource locations, resource keys and values of the
     /// elements.
     #[inline]
-    pub fn iter(&self) -> Iter<'_, T> {
+    pub fn iter(&self) -> Iter<'_, T, Tag> {
         Iter {
             iter: self.index.iter(),
             slice: &self.store,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over resource locations, resource keys and values of the elements, in
+    /// the order of resource keys (i.e. the insertion order).
+    ///
+    /// Unlike [`Registry::iter`], this iterator walks the store rather than the hash map.
+    #[inline]
+    pub fn iter_ordered(&self) -> IterOrdered<'_, T, Tag> {
+        IterOrdered {
+            iter: self.locations.iter().zip(self.store.iter()),
+            index: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over resource locations, resource keys and values of the elements,
+    /// sorted by resource locations (namespace first, then path).
+    ///
+    /// This method collects and sorts the resource locations before iterating.
+    pub fn iter_by_location(&self) -> IterByLocation<'_, T, Tag> {
+        let mut entries: Vec<_> = self.index.iter().map(|(loc, &key)| (loc, key)).collect();
+        entries.sort_unstable_by_key(|&(loc, _)| loc);
+
+        IterByLocation {
+            iter: entries.into_iter(),
+            slice: &self.store,
+            marker: PhantomData,
         }
     }
 
@@ -468,9 +802,46 @@ impl<T> Registry<T> {
         }
     }
 
+    /// Returns an iterator over resource keys and values of the elements, in the order of
+    /// resource keys.
+    ///
+    /// Unlike [`Registry::iter_ordered`], this iterator only walks the store.
+    #[inline]
+    pub fn as_entries(&self) -> Entries<'_, T, Tag> {
+        Entries {
+            iter: self.store.iter(),
+            index: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a parallel iterator over values of the elements.
+    ///
+    /// Only the values are visited in parallel, resource location lookups remain single-threaded.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_values(&self) -> rayon::slice::Iter<'_, T>
+    where
+        T: Sync,
+    {
+        rayon::prelude::IntoParallelRefIterator::par_iter(&self.store)
+    }
+
+    /// Returns a parallel iterator over mutable values of the elements.
+    ///
+    /// Only the values are visited in parallel, resource location lookups remain single-threaded.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_values_mut(&mut self) -> rayon::slice::IterMut<'_, T>
+    where
+        T: Send,
+    {
+        rayon::prelude::IntoParallelRefMutIterator::par_iter_mut(&mut self.store)
+    }
+
     /// Returns `true` if the registry contains an element corresponding to the resource key.
     #[inline]
-    pub fn contains_key(&self, key: ResKey<T>) -> bool {
+    pub fn contains_key(&self, key: ResKey<T, Tag>) -> bool {
         (key.index as usize) < self.store.len()
     }
 
@@ -480,18 +851,48 @@ impl<T> Registry<T> {
         self.index.contains_key(loc)
     }
 
+    /// Returns `true` if the registry contains an element corresponding to the resource location
+    /// in the string form, without constructing a resource location.
+    ///
+    /// The lookup costs hashing the string once (and a temporary string if it has no namespace),
+    /// see [`Registry::get_by_str`]. Invalid strings return `false`.
+    #[inline]
+    pub fn contains_str(&self, s: &str) -> bool {
+        self.find_str(s).is_some()
+    }
+
     /// Returns the reference to the element corresponding to the given resource key.
     #[inline]
-    pub fn get(&self, key: ResKey<T>) -> Option<&T> {
+    pub fn get(&self, key: ResKey<T, Tag>) -> Option<&T> {
         self.store.get(usize::from(key))
     }
 
     /// Returns the mutable reference to the element corresponding to the given resource key.
     #[inline]
-    pub fn get_mut(&mut self, key: ResKey<T>) -> Option<&mut T> {
+    pub fn get_mut(&mut self, key: ResKey<T, Tag>) -> Option<&mut T> {
         self.store.get_mut(usize::from(key))
     }
 
+    /// Returns the mutable references to the elements corresponding to the given resource keys.
+    ///
+    /// Returns `None` if any of the resource keys is out of range, or any two of them are equal.
+    #[inline]
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        keys: [ResKey<T, Tag>; N],
+    ) -> Option<[&mut T; N]> {
+        let indices = keys.map(usize::from);
+        for (i, index) in indices.iter().enumerate() {
+            if *index >= self.store.len() || indices[..i].contains(index) {
+                return None;
+            }
+        }
+
+        let ptr = self.store.as_mut_ptr();
+        // SAFETY: the indices are in range and pairwise distinct, so the references never alias.
+        Some(indices.map(|index| unsafe { &mut *ptr.add(index) }))
+    }
+
     /// Returns the reference to the element corresponding to the given resource location.
     #[inline]
     pub fn get_by_loc(&self, loc: &ResLocation) -> Option<&T> {
@@ -506,9 +907,42 @@ impl<T> Registry<T> {
         self.store.get_mut(*key as usize)
     }
 
+    /// Returns the reference to the element corresponding to the resource location in the string
+    /// form, without constructing a resource location.
+    ///
+    /// The string is interpreted like [`ResLocation::from_str`], i.e. a string without `':'` uses
+    /// the default namespace (which allocates a temporary string). An invalid string never matches
+    /// any element, so it is not validated.
+    ///
+    /// `ResLocation` does not implement `Borrow<str>` for such lookups: `Borrow` requires the
+    /// borrowed form to hash identically, but a resource location hashes its pre-computed hash of
+    /// the string rather than the string itself, and `"stone"` and `"minecrium:stone"` are the
+    /// same location but different strings.
+    pub fn get_by_str(&self, s: &str) -> Option<&T> {
+        let key = self.find_str(s)?;
+        self.store.get(key as usize)
+    }
+
+    /// Returns the index of the element corresponding to the resource location in the string form.
+    fn find_str(&self, s: &str) -> Option<u32> {
+        let location = if s.contains(':') {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(format!("{}:{s}", ResLocation::DEFAULT_NAMESPACE))
+        };
+
+        // hashes the same as `ResLocation::hash`, which feeds the pre-computed hash.
+        let hash = self.index.hasher().hash_one(hashes(&*location));
+        let (_, key) = self
+            .index
+            .raw_entry()
+            .from_hash(hash, |loc| loc.as_str() == location)?;
+        Some(*key)
+    }
+
     /// Returns the resource key corresponding to the resource location.
     #[inline]
-    pub fn get_key(&self, loc: &ResLocation) -> Option<ResKey<T>> {
+    pub fn get_key(&self, loc: &ResLocation) -> Option<ResKey<T, Tag>> {
         let key = self.index.get(loc)?;
         Some(ResKey::from(*key))
     }
@@ -523,7 +957,7 @@ impl<T> Registry<T> {
     /// # Panics
     ///
     /// Panics if the registy contains too many elements (`self.len() > u32::MAX`).
-    pub fn insert(&mut self, loc: ResLocation, value: T) -> Result<ResKey<T>, T> {
+    pub fn insert(&mut self, loc: ResLocation, value: T) -> Result<ResKey<T, Tag>, T> {
         if !self.contains_loc(&loc) {
             // SAFETY: the `loc` is just checked.
             Ok(unsafe { self.insert_unique_unchecked(loc, value) })
@@ -532,6 +966,78 @@ impl<T> Registry<T> {
         }
     }
 
+    /// Insert the resource location and the value into the registry like [`Registry::insert`],
+    /// and calls `hook` with the newly inserted entry.
+    ///
+    /// The `hook` is called after the value is stored, so the registry already contains the
+    /// entry. It is not called if the registry already contains the resource location.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the registy contains too many elements (`self.len() > u32::MAX`).
+    pub fn insert_with_hook<F>(
+        &mut self,
+        loc: ResLocation,
+        value: T,
+        hook: F,
+    ) -> Result<ResKey<T, Tag>, T>
+    where
+        F: FnOnce(&ResLocation, ResKey<T, Tag>, &T),
+    {
+        let key = self.insert(loc, value)?;
+        let index = u32::from(key) as usize;
+        hook(&self.locations[index], key, &self.store[index]);
+        Ok(key)
+    }
+
+    /// Insert the resource location and the value into the registry if the registry does not
+    /// contain the resource location.
+    ///
+    /// Returns the resource key corresponding to the resource location, and the `value` as `Some`
+    /// if the registry already contains the resource location, or `None` if the value is newly
+    /// inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the registy contains too many elements (`self.len() > u32::MAX`).
+    pub fn insert_or_get(&mut self, loc: ResLocation, value: T) -> (ResKey<T, Tag>, Option<T>) {
+        match self.get_key(&loc) {
+            Some(key) => (key, Some(value)),
+            // SAFETY: the `loc` is just checked.
+            None => (unsafe { self.insert_unique_unchecked(loc, value) }, None),
+        }
+    }
+
+    /// Returns the resource key corresponding to the resource location if it exists, otherwise
+    /// inserts the value returned by `f` into the registry and returns the new resource key.
+    ///
+    /// `f` is not called if the registry already contains the resource location.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by `f`, in which case nothing is inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the registy contains too many elements (`self.len() > u32::MAX`).
+    pub fn get_or_try_insert_with<E, F>(
+        &mut self,
+        loc: ResLocation,
+        f: F,
+    ) -> Result<ResKey<T, Tag>, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        match self.get_key(&loc) {
+            Some(key) => Ok(key),
+            None => {
+                let value = f()?;
+                // SAFETY: the `loc` is just checked.
+                Ok(unsafe { self.insert_unique_unchecked(loc, value) })
+            }
+        }
+    }
+
     /// Insert the resource location and the value into the registry without checking if the key
     /// already exists in the map. Returns a resource key corresponding to the newly inserted value.
     ///
@@ -542,9 +1048,10 @@ impl<T> Registry<T> {
     /// # Panics
     ///
     /// Panics if the registy contains too many elements (`self.len() > u32::MAX`).
-    pub unsafe fn insert_unique_unchecked(&mut self, loc: ResLocation, value: T) -> ResKey<T> {
+    pub unsafe fn insert_unique_unchecked(&mut self, loc: ResLocation, value: T) -> ResKey<T, Tag> {
         let key = ResKey::from(u32::try_from(self.store.len()).unwrap());
         self.store.push(value);
+        self.locations.push(loc.clone());
         self.index.insert_unique_unchecked(loc, u32::from(key));
         key
     }
@@ -554,29 +1061,145 @@ impl<T> Registry<T> {
     pub fn reserve(&mut self, additional: usize) {
         self.store.reserve(additional);
         self.index.reserve(additional);
+        self.locations.reserve(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be inserted in the
+    /// registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capacity overflows, or the allocator reports a failure. The
+    /// capacity may be partially reserved in that case.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.store.try_reserve(additional)?;
+        self.locations.try_reserve(additional)?;
+        self.index.try_reserve(additional)?;
+        Ok(())
+    }
+
+    /// Removes all elements from the registry, keeping the allocated memory.
+    ///
+    /// All previously returned resource keys become dangling (out of range), until new elements
+    /// are inserted and the keys are reused.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.store.clear();
+        self.index.clear();
+        self.locations.clear();
+    }
+
+    /// Shrinks the capacity of the registry as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+        self.index.shrink_to_fit();
+        self.locations.shrink_to_fit();
+    }
+
+    /// Returns `true` if the internal invariant of the resource keys holds, i.e. the store, the
+    /// resource locations and the index have the same length, and each resource location in the
+    /// index maps to the resource key which maps back to it.
+    ///
+    /// This method is intended for debugging and tests, and takes `O(n)` time.
+    pub fn assert_dense(&self) -> bool {
+        self.store.len() == self.locations.len()
+            && self.index.len() == self.locations.len()
+            && self
+                .index
+                .iter()
+                .all(|(loc, &key)| self.locations.get(key as usize) == Some(loc))
+    }
+
+    /// Returns a new registry with the same resource locations, where each value is mapped by `f`.
+    ///
+    /// The resource keys are aligned between both registries: a `ResKey<T>` of this registry and
+    /// the `ResKey<U>` with the same index (see [`ErasedResKey::typed`]) refer to the same
+    /// resource location. `f` is called in the order of resource keys.
+    pub fn map<U, F>(&self, f: F) -> Registry<U, Tag>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Registry {
+            store: self.store.iter().map(f).collect(),
+            index: self.index.clone(),
+            locations: self.locations.clone(),
+            marker: PhantomData,
+        }
     }
 }
 
-impl<T> Default for Registry<T> {
+impl<T: PartialEq, Tag> Registry<T, Tag> {
+    /// Returns the differences from `self` (the old registry) to `other` (the new registry), e.g.
+    /// to update clients incrementally after reloading.
+    ///
+    /// The resource keys are not compared, only the resource locations and values. The time
+    /// complexity is `O(n)` over both registries, and each list of the resource locations is in
+    /// the order of resource keys of the registry containing them.
+    pub fn diff(&self, other: &Registry<T, Tag>) -> RegistryDiff {
+        let mut diff = RegistryDiff::default();
+
+        for (loc, value) in self.locations.iter().zip(&self.store) {
+            match other.get_by_loc(loc) {
+                Some(other_value) if other_value != value => diff.changed.push(loc.clone()),
+                Some(_) => (),
+                None => diff.removed.push(loc.clone()),
+            }
+        }
+        for loc in &other.locations {
+            if !self.contains_loc(loc) {
+                diff.added.push(loc.clone());
+            }
+        }
+
+        diff
+    }
+}
+
+/// The differences between two registries, see [`Registry::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegistryDiff {
+    /// the resource locations only in the new registry.
+    pub added: Vec<ResLocation>,
+    /// the resource locations only in the old registry.
+    pub removed: Vec<ResLocation>,
+    /// the resource locations in both registries, but with different values.
+    pub changed: Vec<ResLocation>,
+}
+
+impl RegistryDiff {
+    /// Returns `true` if there is no difference.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<T, Tag> Default for Registry<T, Tag> {
     #[inline]
     fn default() -> Self {
         Self {
             store: Vec::new(),
             index: HashMap::default(),
+            locations: Vec::new(),
+            marker: PhantomData,
         }
     }
 }
 
-impl<T: Clone> Clone for Registry<T> {
+impl<T: Clone, Tag> Clone for Registry<T, Tag> {
     fn clone(&self) -> Self {
         Self {
             store: self.store.clone(),
             index: self.index.clone(),
+            locations: self.locations.clone(),
+            marker: PhantomData,
         }
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for Registry<T> {
+impl<T: fmt::Debug, Tag> fmt::Debug for Registry<T, Tag> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut state = f.debug_map();
 
@@ -588,37 +1211,37 @@ impl<T: fmt::Debug> fmt::Debug for Registry<T> {
     }
 }
 
-impl<T> AsRef<[T]> for Registry<T> {
+impl<T, Tag> AsRef<[T]> for Registry<T, Tag> {
     #[inline]
     fn as_ref(&self) -> &[T] {
         &self.store
     }
 }
 
-impl<T> AsMut<[T]> for Registry<T> {
+impl<T, Tag> AsMut<[T]> for Registry<T, Tag> {
     #[inline]
     fn as_mut(&mut self) -> &mut [T] {
         &mut self.store
     }
 }
 
-impl<T> Index<ResKey<T>> for Registry<T> {
+impl<T, Tag> Index<ResKey<T, Tag>> for Registry<T, Tag> {
     type Output = T;
 
     #[inline]
-    fn index(&self, index: ResKey<T>) -> &Self::Output {
+    fn index(&self, index: ResKey<T, Tag>) -> &Self::Output {
         self.store.index(usize::from(index))
     }
 }
 
-impl<T> IndexMut<ResKey<T>> for Registry<T> {
+impl<T, Tag> IndexMut<ResKey<T, Tag>> for Registry<T, Tag> {
     #[inline]
-    fn index_mut(&mut self, index: ResKey<T>) -> &mut Self::Output {
+    fn index_mut(&mut self, index: ResKey<T, Tag>) -> &mut Self::Output {
         self.store.index_mut(usize::from(index))
     }
 }
 
-impl<T> Index<&ResLocation> for Registry<T> {
+impl<T, Tag> Index<&ResLocation> for Registry<T, Tag> {
     type Output = T;
 
     #[inline]
@@ -628,7 +1251,7 @@ impl<T> Index<&ResLocation> for Registry<T> {
     }
 }
 
-impl<T> IndexMut<&ResLocation> for Registry<T> {
+impl<T, Tag> IndexMut<&ResLocation> for Registry<T, Tag> {
     #[inline]
     fn index_mut(&mut self, index: &ResLocation) -> &mut Self::Output {
         let index = self.index.index(index);
@@ -638,28 +1261,27 @@ impl<T> IndexMut<&ResLocation> for Registry<T> {
 
 /// Returns the hash of the value. Used to compute the `ResLocationInner.hash`.
 fn hashes<T: ?Sized + Hash>(value: &T) -> u64 {
-    let builder = bevy_utils::FixedState;
-    let mut hasher = builder.build_hasher();
-    value.hash(&mut hasher);
-    hasher.finish()
+    bevy_utils::FixedState.hash_one(value)
 }
 
 /// An iterator that is returned by `Registry::iter`.
-pub struct Iter<'a, T> {
+pub struct Iter<'a, T, Tag = ()> {
     iter: bevy_utils::hashbrown::hash_map::Iter<'a, ResLocation, u32>,
     slice: &'a [T],
+    marker: PhantomData<fn() -> Tag>,
 }
 
-impl<'a, T> Clone for Iter<'a, T> {
+impl<'a, T, Tag> Clone for Iter<'a, T, Tag> {
     fn clone(&self) -> Self {
         Self {
             iter: self.iter.clone(),
             slice: self.slice,
+            marker: PhantomData,
         }
     }
 }
 
-impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+impl<'a, T: fmt::Debug, Tag> fmt::Debug for Iter<'a, T, Tag> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut state = f.debug_map();
 
@@ -671,8 +1293,8 @@ impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = (&'a ResLocation, ResKey<T>, &'a T);
+impl<'a, T, Tag> Iterator for Iter<'a, T, Tag> {
+    type Item = (&'a ResLocation, ResKey<T, Tag>, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
         let (loc, &key) = self.iter.next()?;
@@ -687,9 +1309,107 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
-impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+impl<'a, T, Tag> ExactSizeIterator for Iter<'a, T, Tag> {}
+
+impl<'a, T, Tag> FusedIterator for Iter<'a, T, Tag> {}
 
-impl<'a, T> FusedIterator for Iter<'a, T> {}
+/// An iterator that is returned by `Registry::iter_ordered`.
+pub struct IterOrdered<'a, T, Tag = ()> {
+    iter: std::iter::Zip<std::slice::Iter<'a, ResLocation>, std::slice::Iter<'a, T>>,
+    index: u32,
+    marker: PhantomData<fn() -> Tag>,
+}
+
+impl<'a, T, Tag> Clone for IterOrdered<'a, T, Tag> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            index: self.index,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, Tag> fmt::Debug for IterOrdered<'a, T, Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut state = f.debug_map();
+
+        for (key, _, value) in self.clone() {
+            state.entry(key, value);
+        }
+
+        state.finish()
+    }
+}
+
+impl<'a, T, Tag> Iterator for IterOrdered<'a, T, Tag> {
+    type Item = (&'a ResLocation, ResKey<T, Tag>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (loc, value) = self.iter.next()?;
+        let key = ResKey::from(self.index);
+        self.index += 1;
+        Some((loc, key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, Tag> ExactSizeIterator for IterOrdered<'a, T, Tag> {}
+
+impl<'a, T, Tag> FusedIterator for IterOrdered<'a, T, Tag> {}
+
+/// An iterator that is returned by `Registry::iter_by_location`.
+pub struct IterByLocation<'a, T, Tag = ()> {
+    iter: std::vec::IntoIter<(&'a ResLocation, u32)>,
+    slice: &'a [T],
+    marker: PhantomData<fn() -> Tag>,
+}
+
+impl<'a, T, Tag> Clone for IterByLocation<'a, T, Tag> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            slice: self.slice,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, Tag> fmt::Debug for IterByLocation<'a, T, Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut state = f.debug_map();
+
+        for (key, _, value) in self.clone() {
+            state.entry(key, value);
+        }
+
+        state.finish()
+    }
+}
+
+impl<'a, T, Tag> Iterator for IterByLocation<'a, T, Tag> {
+    type Item = (&'a ResLocation, ResKey<T, Tag>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (loc, key) = self.iter.next()?;
+        let value = self.slice.index(key as usize);
+        let key = ResKey::from(key);
+        Some((loc, key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, Tag> ExactSizeIterator for IterByLocation<'a, T, Tag> {}
+
+impl<'a, T, Tag> FusedIterator for IterByLocation<'a, T, Tag> {}
 
 /// An iterator that is returned by `Registry::keys`.
 pub struct Keys<'a, T> {
@@ -768,6 +1488,51 @@ impl<'a, T> ExactSizeIterator for Values<'a, T> {}
 
 impl<'a, T> FusedIterator for Values<'a, T> {}
 
+/// An iterator that is returned by `Registry::as_entries`.
+pub struct Entries<'a, T, Tag = ()> {
+    iter: std::slice::Iter<'a, T>,
+    index: u32,
+    marker: PhantomData<fn() -> Tag>,
+}
+
+impl<'a, T, Tag> Clone for Entries<'a, T, Tag> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            index: self.index,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, Tag> fmt::Debug for Entries<'a, T, Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T, Tag> Iterator for Entries<'a, T, Tag> {
+    type Item = (ResKey<T, Tag>, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        let key = ResKey::from(self.index);
+        self.index += 1;
+        Some((key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, Tag> ExactSizeIterator for Entries<'a, T, Tag> {}
+
+impl<'a, T, Tag> FusedIterator for Entries<'a, T, Tag> {}
+
 #[cfg(test)]
 mod tests {
     use crate::resource::*;
@@ -801,12 +1566,510 @@ mod tests {
 
         assert_eq!(
             ResLocation::from_str("Minecraft:dirt"),
-            Err(ResLocationError::NamespaceError)
+            Err(ResLocationError::NamespaceError { ch: 'M', pos: 0 })
         );
 
         assert_eq!(
             ResLocation::from_str("minecraft:Dirt"),
-            Err(ResLocationError::PathError)
+            Err(ResLocationError::PathError { ch: 'D', pos: 0 })
+        );
+
+        assert_eq!(
+            ResLocation::from_str("minecraft:ore/coal ore"),
+            Err(ResLocationError::PathError { ch: ' ', pos: 8 })
+        );
+
+        assert_eq!(
+            ResLocation::from_str("minécraft:dirt"),
+            Err(ResLocationError::NamespaceError { ch: 'é', pos: 3 })
+        );
+
+        assert_eq!(
+            ResLocation::from_str("minecraft:Dirt")
+                .unwrap_err()
+                .to_string(),
+            "the resource location path has non [a-z0-9_.-/] char 'D' at 0"
+        );
+    }
+
+    #[test]
+    fn test_resource_location_eq_str() {
+        let loc = ResLocation::from_str("minecraft:dirt").unwrap();
+        assert!(loc.eq_str("minecraft:dirt"));
+        assert!(!loc.eq_str("minecraft:stone"));
+        assert!(!loc.eq_str("dirt"));
+        assert_eq!(loc, *"minecraft:dirt");
+        assert_ne!(loc, *"minecrium:dirt");
+        assert!(loc == "minecraft:dirt");
+        assert!("minecraft:dirt" == loc);
+        assert!(*"minecraft:dirt" == loc);
+        assert!("minecraft:stone" != loc);
+    }
+
+    #[test]
+    fn test_resource_location_ord() {
+        let loc = |s| ResLocation::from_str(s).unwrap();
+
+        assert!(loc("minecraft:a") < loc("minecraft:b"));
+        assert!(loc("minecraft:b") < loc("zzz:a"));
+        assert!(loc("a:z") < loc("a-b:a"));
+        assert_eq!(
+            loc("minecraft:a").cmp(&loc("minecraft:a")),
+            std::cmp::Ordering::Equal
+        );
+
+        let mut locations = vec![
+            loc("zzz:a"),
+            loc("minecraft:b"),
+            loc("a-b:a"),
+            loc("minecraft:a"),
+        ];
+        locations.sort();
+        assert_eq!(
+            locations,
+            [
+                loc("a-b:a"),
+                loc("minecraft:a"),
+                loc("minecraft:b"),
+                loc("zzz:a")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resource_location_asset_path() {
+        use std::path::Path;
+
+        let loc = ResLocation::from_str("minecraft:block/stone").unwrap();
+        assert_eq!(
+            loc.to_asset_path("models", Some("json")),
+            Some(Path::new("assets/minecraft/models/block/stone.json").to_owned())
+        );
+        assert_eq!(
+            loc.to_asset_path("textures/item", None),
+            Some(Path::new("assets/minecraft/textures/item/block/stone").to_owned())
+        );
+
+        // escaping or malformed contexts.
+        for ctx in [
+            "",
+            ".",
+            "..",
+            "../models",
+            "models/..",
+            "models//block",
+            "/models",
+            "a\\b",
+            "c:",
+        ] {
+            assert_eq!(loc.to_asset_path(ctx, Some("json")), None, "{ctx:?}");
+        }
+        // escaping or malformed extensions.
+        for ext in ["", "a/b", "a\\b", "c:"] {
+            assert_eq!(loc.to_asset_path("models", Some(ext)), None, "{ext:?}");
+        }
+        // escaping paths, which are valid resource locations.
+        let loc = ResLocation::from_str("minecraft:../stone").unwrap();
+        assert_eq!(loc.to_asset_path("models", None), None);
+        let loc = ResLocation::from_str("minecraft:block/").unwrap();
+        assert_eq!(loc.to_asset_path("models", None), None);
+    }
+
+    #[test]
+    fn test_resource_location_lossy() {
+        let lossy = |namespace, path| {
+            let (loc, changed) = ResLocation::new_lossy(namespace, path);
+            (loc.as_str().to_owned(), changed)
+        };
+
+        assert_eq!(
+            lossy("minecraft", "block/stone"),
+            ("minecraft:block/stone".into(), false)
+        );
+        assert_eq!(
+            lossy("Minecraft", "STONE"),
+            ("minecraft:stone".into(), true)
+        );
+        assert_eq!(lossy("my mod", "a:b"), ("my_mod:a_b".into(), true));
+        assert_eq!(lossy("a/b", "c/d"), ("a_b:c/d".into(), true));
+        assert_eq!(lossy("café", "ÉTÉ"), ("caf_:_t_".into(), true));
+        assert_eq!(lossy("", "stone"), ("minecrium:stone".into(), true));
+        assert_eq!(lossy("minecraft", ""), ("minecraft:_".into(), true));
+
+        let (loc, _) = ResLocation::new_lossy("Minecraft", "Dirt");
+        assert_eq!(loc, ResLocation::from_str("minecraft:dirt").unwrap());
+    }
+
+    #[test]
+    fn test_resource_location_unreserved() {
+        const RESERVED: &[&str] = &["minecraft", ResLocation::DEFAULT_NAMESPACE];
+
+        assert_eq!(
+            ResLocation::new_unreserved("minecraft", "foo", RESERVED),
+            Err(ReservedNamespaceError::Reserved)
+        );
+
+        assert_eq!(
+            ResLocation::new_unreserved("minecrium", "foo", RESERVED),
+            Err(ReservedNamespaceError::Reserved)
+        );
+
+        assert_eq!(
+            ResLocation::new_unreserved("my_mod", "", RESERVED),
+            Err(ReservedNamespaceError::Invalid(ResLocationError::PathEmpty))
+        );
+
+        assert_eq!(
+            ResLocation::new_unreserved("my_mod", "foo", RESERVED).unwrap(),
+            ResLocation::from_str("my_mod:foo").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_erased_resource_key() {
+        let key = ResKey::<String>::from(7);
+        let erased = key.erase();
+        assert_eq!(erased, ErasedResKey::from(key));
+        assert_eq!(erased.typed::<String>(), key);
+        assert_eq!(u32::from(erased.typed::<i32>()), 7);
+    }
+
+    #[test]
+    fn test_registry_tagged() {
+        struct Items;
+
+        let mut registry = Registry::<u32>::new_tagged::<Items>();
+        let stone = registry
+            .insert(ResLocation::from_str("stone").unwrap(), 1)
+            .unwrap();
+        let _: ResKey<u32, Items> = stone;
+        assert_eq!(registry[stone], 1);
+
+        for (loc, key, value) in registry.iter_ordered() {
+            assert_eq!(registry.get_key(loc), Some(key));
+            assert_eq!(registry[key], *value);
+        }
+
+        let mapped = registry.map(|value| value * 2);
+        let key = stone.erase().typed_tagged::<u32, Items>();
+        assert_eq!(mapped[key], 2);
+
+        // a tag that is neither `Send` nor `Sync` does not affect the registry.
+        fn assert_send_sync<T: Send + Sync>() {}
+        struct NotSync(std::marker::PhantomData<*const ()>);
+        assert_send_sync::<Registry<u32, NotSync>>();
+        assert_send_sync::<ResKey<u32, NotSync>>();
+        assert_send_sync::<Iter<'_, u32, NotSync>>();
+
+        let registry = Registry::<u32>::with_capacity_tagged::<Items>(16);
+        let _: &Registry<u32, Items> = &registry;
+        assert!(registry.is_empty());
+        assert!(registry.capacity() >= 16);
+    }
+
+    #[test]
+    fn test_registry_get_by_str() {
+        let mut registry = Registry::new();
+        registry
+            .insert(ResLocation::from_str("minecraft:stone").unwrap(), 1)
+            .unwrap();
+        registry
+            .insert(ResLocation::from_str("dirt").unwrap(), 2)
+            .unwrap();
+
+        assert_eq!(registry.get_by_str("minecraft:stone"), Some(&1));
+        assert_eq!(registry.get_by_str("minecrium:dirt"), Some(&2));
+        assert_eq!(registry.get_by_str("dirt"), Some(&2));
+        assert_eq!(registry.get_by_str("stone"), None);
+        assert_eq!(registry.get_by_str("minecraft:dirt"), None);
+        assert_eq!(registry.get_by_str("Minecraft:stone"), None);
+        assert_eq!(registry.get_by_str(""), None);
+
+        assert!(registry.contains_str("minecraft:stone"));
+        assert!(registry.contains_str("dirt"));
+        assert!(registry.contains_str("minecrium:dirt"));
+        assert!(!registry.contains_str("stone"));
+        assert!(!registry.contains_str("Minecraft:stone"));
+        assert!(!registry.contains_str("minecraft:"));
+    }
+
+    #[test]
+    fn test_registry_insert_or_get() {
+        let mut registry = Registry::new();
+        let stone = ResLocation::from_str("minecraft:stone").unwrap();
+
+        let (key, value) = registry.insert_or_get(stone.clone(), 1);
+        assert_eq!(value, None);
+        assert_eq!(registry[key], 1);
+
+        assert_eq!(registry.insert_or_get(stone, 2), (key, Some(2)));
+        assert_eq!(registry[key], 1);
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_registry_insert_with_hook() {
+        let mut registry = Registry::new();
+        let stone = ResLocation::from_str("minecraft:stone").unwrap();
+        let mut inserted = Vec::new();
+
+        let key = registry
+            .insert_with_hook(stone.clone(), 1, |loc, key, value| {
+                inserted.push((loc.clone(), key, *value));
+            })
+            .unwrap();
+        assert_eq!(inserted, [(stone.clone(), key, 1)]);
+
+        let result = registry.insert_with_hook(stone, 2, |_, _, _| panic!());
+        assert_eq!(result, Err(2));
+        assert_eq!(registry[key], 1);
+    }
+
+    #[test]
+    fn test_registry_get_or_try_insert_with() {
+        let mut registry = Registry::new();
+        let stone = ResLocation::from_str("minecraft:stone").unwrap();
+        let dirt = ResLocation::from_str("minecraft:dirt").unwrap();
+        let key = registry.insert(stone.clone(), 1).unwrap();
+
+        // the existing key path.
+        let result = registry.get_or_try_insert_with(stone, || -> Result<_, ()> { panic!() });
+        assert_eq!(result, Ok(key));
+        assert_eq!(registry.len(), 1);
+
+        // the error path.
+        let result = registry.get_or_try_insert_with(dirt.clone(), || Err("error"));
+        assert_eq!(result, Err("error"));
+        assert_eq!(registry.len(), 1);
+        assert!(!registry.contains_loc(&dirt));
+
+        // the successful insertion path.
+        let result = registry.get_or_try_insert_with(dirt.clone(), || Ok::<_, ()>(2));
+        let key = result.unwrap();
+        assert_eq!(registry.get_key(&dirt), Some(key));
+        assert_eq!(registry[key], 2);
+    }
+
+    #[test]
+    fn test_registry_get_many_mut() {
+        let mut registry = Registry::new();
+        let stone = registry
+            .insert(ResLocation::from_str("stone").unwrap(), 1)
+            .unwrap();
+        let dirt = registry
+            .insert(ResLocation::from_str("dirt").unwrap(), 2)
+            .unwrap();
+        let sand = ResKey::from(2);
+
+        let [a, b] = registry.get_many_mut([stone, dirt]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(registry[stone], 2);
+        assert_eq!(registry[dirt], 1);
+
+        assert!(registry.get_many_mut([stone, stone]).is_none());
+        assert!(registry.get_many_mut([stone, sand]).is_none());
+        assert!(registry.get_many_mut([dirt, stone, dirt]).is_none());
+        assert_eq!(registry.get_many_mut([dirt, stone]), Some([&mut 1, &mut 2]));
+        assert_eq!(registry.get_many_mut([]), Some([]));
+    }
+
+    #[test]
+    fn test_registry_map() {
+        let mut registry = Registry::new();
+        for (value, path) in ["stone", "dirt", "grass"].into_iter().enumerate() {
+            let loc = ResLocation::from_str(path).unwrap();
+            registry.insert(loc, value).unwrap();
+        }
+
+        let mut order = Vec::new();
+        let mapped = registry.map(|value| {
+            order.push(*value);
+            value.to_string()
+        });
+        assert_eq!(order, [0, 1, 2]);
+        assert_eq!(mapped.len(), registry.len());
+
+        for (loc, key, value) in registry.iter_ordered() {
+            let mapped_key = key.erase().typed::<String>();
+            assert_eq!(mapped.get_key(loc), Some(mapped_key));
+            assert_eq!(mapped[mapped_key], value.to_string());
+        }
+    }
+
+    #[test]
+    fn test_registry_try_reserve() {
+        let mut registry = Registry::<u64>::new();
+        assert_eq!(registry.try_reserve(16), Ok(()));
+        assert!(registry.capacity() >= 16);
+
+        let err = registry.try_reserve(usize::MAX).unwrap_err();
+        assert!(matches!(err, TryReserveError::Store(_)));
+        assert!(std::error::Error::source(&err).is_some());
+        assert!(registry.capacity() >= 16);
+    }
+
+    #[test]
+    fn test_registry_diff() {
+        let loc = |s| ResLocation::from_str(s).unwrap();
+
+        let mut old = Registry::new();
+        for (path, value) in [("stone", 1), ("dirt", 2), ("sand", 3), ("gravel", 4)] {
+            old.insert(loc(path), value).unwrap();
+        }
+        let mut new = Registry::new();
+        for (path, value) in [
+            ("grass", 5),
+            ("gravel", 4),
+            ("dirt", 0),
+            ("stone", 1),
+            ("ice", 6),
+        ] {
+            new.insert(loc(path), value).unwrap();
+        }
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added, [loc("grass"), loc("ice")]);
+        assert_eq!(diff.removed, [loc("sand")]);
+        assert_eq!(diff.changed, [loc("dirt")]);
+        assert!(!diff.is_empty());
+
+        let diff = new.diff(&old);
+        assert_eq!(diff.added, [loc("sand")]);
+        assert_eq!(diff.removed, [loc("grass"), loc("ice")]);
+        assert_eq!(diff.changed, [loc("dirt")]);
+
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_registry_key_stability() {
+        let mut registry = Registry::new();
+        assert!(registry.assert_dense());
+
+        let paths = ["stone", "dirt", "grass", "sand", "gravel"];
+        for (i, path) in paths.into_iter().enumerate() {
+            let key = registry
+                .insert(ResLocation::from_str(path).unwrap(), i * 10)
+                .unwrap();
+            assert_eq!(u32::from(key), i as u32);
+            assert!(registry.assert_dense());
+        }
+
+        for (loc, key, value) in registry.iter() {
+            assert_eq!(registry.get(key), Some(value));
+            assert_eq!(registry.get_key(loc), Some(key));
+            assert_eq!(*value, u32::from(key) as usize * 10);
+        }
+
+        registry.clear();
+        assert!(registry.assert_dense());
+    }
+
+    #[test]
+    fn test_registry_clear() {
+        let mut registry = Registry::new();
+        let stone = ResLocation::from_str("minecraft:stone").unwrap();
+        let key = registry.insert(stone.clone(), 1).unwrap();
+
+        registry.clear();
+        assert_eq!(registry.len(), 0);
+        assert!(registry.is_empty());
+        assert_eq!(registry.get(key), None);
+        assert!(!registry.contains_key(key));
+        assert!(!registry.contains_loc(&stone));
+        assert_eq!(registry.iter_ordered().count(), 0);
+
+        assert_eq!(registry.insert(stone, 2), Ok(key));
+    }
+
+    #[test]
+    fn test_registry_iter_ordered() {
+        let mut registry = Registry::new();
+        let paths = ["stone", "dirt", "grass", "sand", "gravel"];
+
+        for (value, path) in paths.iter().enumerate() {
+            let loc = ResLocation::new("minecraft", path).unwrap();
+            registry.insert(loc, value).unwrap();
+        }
+
+        let mut iter = registry.iter_ordered();
+        assert_eq!(iter.len(), paths.len());
+
+        for (value, path) in paths.iter().enumerate() {
+            let (loc, key, &v) = iter.next().unwrap();
+            assert_eq!(loc.path(), *path);
+            assert_eq!(usize::from(key), value);
+            assert_eq!(v, value);
+        }
+
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_registry_as_entries() {
+        let mut registry = Registry::new();
+        let mut keys = Vec::new();
+        for (value, path) in ["stone", "dirt", "grass"].into_iter().enumerate() {
+            keys.push(
+                registry
+                    .insert(ResLocation::from_str(path).unwrap(), value)
+                    .unwrap(),
+            );
+        }
+
+        let entries = registry.as_entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(format!("{entries:?}"), "{0: 0, 1: 1, 2: 2}");
+        for ((key, value), expected) in entries.zip(keys) {
+            assert_eq!(key, expected);
+            assert_eq!(registry[key], *value);
+        }
+    }
+
+    #[test]
+    fn test_registry_iter_by_location() {
+        let mut registry = Registry::new();
+        let locations = ["minecraft:stone", "a:z", "minecraft:dirt", "a.b:c", "zzz:a"];
+
+        for (value, loc) in locations.iter().enumerate() {
+            let loc = ResLocation::from_str(loc).unwrap();
+            registry.insert(loc, value).unwrap();
+        }
+
+        let sorted: Vec<_> = registry
+            .iter_by_location()
+            .map(|(loc, key, &value)| (loc.as_str(), usize::from(key), value))
+            .collect();
+
+        assert_eq!(
+            sorted,
+            [
+                ("a:z", 1, 1),
+                ("a.b:c", 3, 3),
+                ("minecraft:dirt", 2, 2),
+                ("minecraft:stone", 0, 0),
+                ("zzz:a", 4, 4),
+            ]
         );
     }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_registry_par_values() {
+        use rayon::prelude::*;
+
+        let mut registry = Registry::new();
+
+        for value in 0..1024 {
+            let loc = ResLocation::new("minecraft", &format!("block_{value}")).unwrap();
+            registry.insert(loc, value).unwrap();
+        }
+
+        registry.par_values_mut().for_each(|value| *value *= 2);
+        assert_eq!(registry.par_values().sum::<i32>(), 1023 * 1024);
+
+        for (_, key, value) in registry.iter() {
+            assert_eq!(*value as usize, 2 * usize::from(key));
+        }
+    }
 }
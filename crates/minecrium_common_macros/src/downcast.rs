@@ -76,6 +76,19 @@ fn impl_downcast(crate_path: &Path, trait_name: &TokenStream) -> TokenStream {
         pub fn downcast_ref<T: #trait_name>(&self) -> std::option::Option<&T> {
             #crate_path::dynamic::AsAny::as_any(self).downcast_ref()
         }
+        /// Returns the downcast value as `&T`.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error with the source and destination type names if `self.is::<T>()`
+        /// evaluates to `false`.
+        #[inline]
+        pub fn try_downcast_ref<T: #trait_name>(&self) -> std::result::Result<&T, #crate_path::errors::DowncastError> {
+            match self.downcast_ref() {
+                Some(value) => Ok(value),
+                None => Err(#crate_path::errors::DowncastError::new::<T>(#crate_path::dynamic::AsAny::type_name(self))),
+            }
+        }
         /// Returns the downcast value as `&mut T`.
         ///
         /// Returns `None` if `self.is::<T>()` evaluates to `false`.
@@ -114,8 +127,19 @@ fn impl_downcast(crate_path: &Path, trait_name: &TokenStream) -> TokenStream {
     }
 }
 
-fn impl_downcast_sync(_: &Path, trait_name: &TokenStream) -> TokenStream {
+fn impl_downcast_sync(crate_path: &Path, trait_name: &TokenStream) -> TokenStream {
     quote::quote! {
+        /// Returns the downcast value as [`Box<T>`](std::boxed::Box).
+        ///
+        /// # Errors
+        ///
+        /// Returns the value as `Box<dyn Any + Send + Sync>` if `self.is::<T>()` evaluates to
+        /// `false`, so the error can still be sent across threads.
+        #[inline]
+        #[rustfmt::skip]
+        pub fn downcast_boxed<T: #trait_name>(self: std::boxed::Box<Self>) -> std::result::Result<std::boxed::Box<T>, std::boxed::Box<dyn std::any::Any + std::marker::Send + std::marker::Sync>> {
+            #crate_path::dynamic::AsAnySync::into_any_sync(self).downcast()
+        }
         /// Returns the downcast value as [`Arc<T>`](std::sync::Arc).
         ///
         /// Returns the trait object if `self.is::<T>()` evaluates to `false`.
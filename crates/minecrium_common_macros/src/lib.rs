@@ -12,6 +12,9 @@ mod downcast;
 ///     /// Returns the downcast value as `&T`.
 ///     pub fn downcast_ref<T: TRAIT>(&self) -> Option<&T>;
 ///
+///     /// Returns the downcast value as `&T`, or an error with the type names.
+///     pub fn try_downcast_ref<T: TRAIT>(&self) -> Result<&T, DowncastError>;
+///
 ///     /// Returns the downcast value as `&mut T`.
 ///     pub fn downcast_mut<T: TRAIT>(&mut self) -> Option<&mut T>;
 ///
@@ -44,6 +47,9 @@ pub fn downcast(attrs: TokenStream, input: TokenStream) -> TokenStream {
 ///     /// Returns the downcast value as `&T`.
 ///     pub fn downcast_ref<T: TRAIT>(&self) -> Option<&T>;
 ///
+///     /// Returns the downcast value as `&T`, or an error with the type names.
+///     pub fn try_downcast_ref<T: TRAIT>(&self) -> Result<&T, DowncastError>;
+///
 ///     /// Returns the downcast value as `&mut T`.
 ///     pub fn downcast_mut<T: TRAIT>(&mut self) -> Option<&mut T>;
 ///
@@ -52,7 +58,10 @@ pub fn downcast(attrs: TokenStream, input: TokenStream) -> TokenStream {
 ///
 ///     /// Returns the downcast value as `Rc<T>`.
 ///     pub fn downcast_rc<T: TRAIT>(self: Rc<Self>) -> Result<Rc<T>, Rc<dyn TRAIT>>;
-
+///
+///     /// Returns the downcast value as `Box<T>`, or `Box<dyn Any + Send + Sync>` on error.
+///     pub fn downcast_boxed<T: TRAIT>(self: Box<Self>) -> Result<Box<T>, Box<dyn Any + Send + Sync>>;
+///
 ///     /// Returns the downcast value as `Arc<T>`.
 ///     pub fn downcast_arc<T: TRAIT>(self: Arc<Self>) -> Result<Arc<T>, Arc<dyn TRAIT>>;
 /// }